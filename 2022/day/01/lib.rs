@@ -0,0 +1,51 @@
+use aoc_runner::Solution;
+use color_eyre::Result;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 1;
+
+    type Parsed = Vec<u32>;
+    type Prepared = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        let mut elf_bags: Vec<Vec<u32>> = vec![vec![]];
+
+        for line in input.lines() {
+            if line.is_empty() {
+                elf_bags.push(vec![])
+            } else {
+                let last = elf_bags.last_mut().unwrap();
+                last.push(line.parse()?);
+            }
+        }
+
+        Ok(elf_bags
+            .into_iter()
+            .map(|bag| bag.iter().sum::<u32>())
+            .collect())
+    }
+
+    fn prepare(mut elf_calories: Self::Parsed) -> Result<Self::Prepared> {
+        elf_calories.sort();
+        Ok(elf_calories)
+    }
+
+    fn part1(elf_calories: &Self::Prepared) -> Result<Self::Answer1> {
+        Ok(*elf_calories.last().unwrap())
+    }
+
+    fn part2(elf_calories: &Self::Prepared) -> Result<Self::Answer2> {
+        let top_3 = &elf_calories[elf_calories.len() - 3..];
+        Ok(top_3.iter().sum::<u32>())
+    }
+}
+
+aoc_runner::examples! {
+    Day01,
+    "test_input" => { part1: 24000, part2: 45000 },
+}
@@ -0,0 +1,127 @@
+use aoc_runner::Solution;
+use color_eyre::Result;
+use std::{collections::HashSet, str::FromStr};
+use thiserror::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Item(char);
+
+impl Item {
+    fn new(c: char) -> Option<Self> {
+        if c.is_alphabetic() {
+            Some(Self(c))
+        } else {
+            None
+        }
+    }
+
+    fn inherent_points(self) -> u32 {
+        match self.0 {
+            'a'..='z' => (self.0 as u8 - b'a' + 1).into(),
+            'A'..='Z' => (self.0 as u8 - b'A' + 27).into(),
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Rucksack {
+    storage: (Vec<Item>, Vec<Item>),
+}
+
+impl Rucksack {
+    fn duplicated_item(&self) -> Option<Item> {
+        self.storage
+            .0
+            .iter()
+            .find(|x| self.storage.1.contains(x))
+            .copied()
+    }
+
+    fn items(&self) -> Vec<Item> {
+        let mut res = self.storage.0.clone();
+        res.extend(self.storage.1.iter());
+        res
+    }
+
+    fn badge(first: &Self, second: &Self, third: &Self) -> Option<Item> {
+        let hash_1: HashSet<_> = first.items().into_iter().collect();
+        let hash_2: HashSet<_> = second.items().into_iter().collect();
+        let hash_3: HashSet<_> = third.items().into_iter().collect();
+
+        let collect: HashSet<_> = hash_1.intersection(&hash_2).copied().collect();
+        collect.intersection(&hash_3).next().copied()
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ParseRucksackError {
+    #[error("odd length")]
+    OddLength,
+    #[error("multiline string")]
+    MultilineStr,
+}
+
+impl FromStr for Rucksack {
+    type Err = ParseRucksackError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+        let (Some(line), None) = (lines.next(), lines.next()) else {
+            return Err(ParseRucksackError::MultilineStr);
+        };
+        let mut line: Vec<_> = line.chars().filter_map(Item::new).collect();
+        let len = line.len();
+        if len % 2 == 1 {
+            Err(ParseRucksackError::OddLength)
+        } else {
+            let last = line.split_off(len / 2);
+            Ok(Self {
+                storage: (line, last),
+            })
+        }
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 3;
+
+    type Parsed = Vec<Rucksack>;
+    type Prepared = Vec<Rucksack>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+    }
+
+    fn prepare(rucksacks: Self::Parsed) -> Result<Self::Prepared> {
+        Ok(rucksacks)
+    }
+
+    fn part1(rucksacks: &Self::Prepared) -> Result<Self::Answer1> {
+        Ok(rucksacks
+            .iter()
+            .map(|rucksack| rucksack.duplicated_item().unwrap().inherent_points())
+            .sum())
+    }
+
+    fn part2(rucksacks: &Self::Prepared) -> Result<Self::Answer2> {
+        Ok(rucksacks
+            .chunks_exact(3)
+            .map(|group| {
+                Rucksack::badge(&group[0], &group[1], &group[2])
+                    .unwrap()
+                    .inherent_points()
+            })
+            .sum())
+    }
+}
+
+aoc_runner::examples! {
+    Day03,
+    "test_input" => { part1: 157, part2: 70 },
+}
@@ -0,0 +1,92 @@
+use std::{
+    io,
+    ops::{Deref, RangeInclusive},
+    str::FromStr,
+};
+
+use aoc_runner::Solution;
+use color_eyre::{Report, Result};
+
+#[allow(dead_code)]
+fn empty_option_err() -> Report {
+    io::Error::other("no hyphen found").into()
+}
+
+#[derive(Debug, Clone)]
+pub struct SectionRange(RangeInclusive<u32>);
+
+impl SectionRange {
+    fn contains_range(&self, other: &SectionRange) -> bool {
+        self.contains(other.start()) && self.contains(other.end())
+    }
+
+    fn contains_or_is_contained_by(&self, other: &SectionRange) -> bool {
+        self.contains_range(other) || other.contains_range(self)
+    }
+
+    fn overlaps(&self, other: &SectionRange) -> bool {
+        self.contains(other.start()) || other.contains(self.start())
+    }
+}
+
+impl Deref for SectionRange {
+    type Target = RangeInclusive<u32>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl FromStr for SectionRange {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s.split_once('-').ok_or_else(empty_option_err)?;
+        let (start, end) = (start.parse::<u32>()?, end.parse::<u32>()?);
+        Ok(Self(start..=end))
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 4;
+
+    type Parsed = Vec<(SectionRange, SectionRange)>;
+    type Prepared = Self::Parsed;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        input
+            .lines()
+            .map(|line| {
+                let (l, r) = line
+                    .split_once(',')
+                    .ok_or_else(|| io::Error::other("no comma found"))?;
+                Ok((l.parse::<SectionRange>()?, r.parse::<SectionRange>()?))
+            })
+            .collect()
+    }
+
+    fn prepare(pairs: Self::Parsed) -> Result<Self::Prepared> {
+        Ok(pairs)
+    }
+
+    fn part1(pairs: &Self::Prepared) -> Result<Self::Answer1> {
+        Ok(pairs
+            .iter()
+            .filter(|(l, r)| l.contains_or_is_contained_by(r))
+            .count())
+    }
+
+    fn part2(pairs: &Self::Prepared) -> Result<Self::Answer2> {
+        Ok(pairs.iter().filter(|(l, r)| l.overlaps(r)).count())
+    }
+}
+
+aoc_runner::examples! {
+    Day04,
+    "test_input" => { part1: 2, part2: 4 },
+}
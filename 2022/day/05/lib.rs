@@ -0,0 +1,188 @@
+use aoc_runner::Solution;
+use color_eyre::{Report, Result};
+
+use crate::parse::{parse_input, Crate, CrateStack, Move};
+#[allow(dead_code)]
+fn empty_option_err() -> Report {
+    use std::io::{Error, ErrorKind::Other};
+    Error::new(Other, "Option was empty").into()
+}
+
+#[allow(dead_code)]
+fn err(msg: &str) -> Report {
+    use std::io::{Error, ErrorKind::Other};
+    Error::new(Other, msg).into()
+}
+
+mod parse {
+    use aoc_runner::parse::IResult;
+    use nom::{
+        branch::alt,
+        character::complete::{
+            char, digit1, line_ending, multispace0, multispace1, one_of, space1,
+        },
+        combinator::{map, map_res},
+        multi::separated_list1,
+        sequence::{delimited, preceded, terminated, tuple},
+    };
+    use nom_supreme::tag::complete::tag;
+    use std::str::FromStr;
+
+    pub type CrateStack = Vec<Crate>;
+
+    #[derive(Debug, Clone, Copy)]
+    pub struct Crate(u8);
+
+    impl Crate {
+        pub fn as_char(self) -> char {
+            self.0 as char
+        }
+    }
+
+    fn stacks(input: &str) -> IResult<&str, Vec<CrateStack>> {
+        separated_list1(
+            line_ending,
+            separated_list1(
+                tag(" "),
+                alt((
+                    delimited(char('['), one_of("ABCDEFGHIJKLMNOPQRSTUVWXYZ"), char(']')),
+                    delimited(char(' '), char(' '), char(' ')),
+                )),
+            ),
+        )(input)
+        .map(|(s, v)| {
+            let mut v: Vec<Vec<_>> = v
+                .into_iter()
+                .map(|s| {
+                    s.into_iter()
+                        .map(|c| match c {
+                            ' ' => None,
+                            c @ 'A'..='Z' => Some(Crate(c as u8)),
+                            _ => unreachable!(),
+                        })
+                        .rev()
+                        .collect()
+                })
+                .rev()
+                .collect();
+            let mut res: Vec<Vec<Crate>> = vec![vec![]; v[0].len()];
+            for ele in res.iter_mut() {
+                ele.extend(v.iter_mut().filter_map(|v| v.pop().flatten()));
+            }
+            (s, res)
+        })
+    }
+
+    fn print_stack(stack: &CrateStack) -> String {
+        stack.iter().copied().map(Crate::as_char).collect()
+    }
+
+    #[allow(dead_code)]
+    pub fn print_stacks(stacks: &[CrateStack]) -> String {
+        stacks
+            .iter()
+            .enumerate()
+            .map(|(i, s)| format!("{}: {}\n", i + 1, print_stack(s)))
+            .collect()
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    pub struct Move {
+        count: u32,
+        from: u32,
+        to: u32,
+    }
+
+    fn borrow_at_mut<T>(s: &mut [T], one: usize, two: usize) -> (&mut T, &mut T) {
+        assert_ne!(one, two);
+        if one < two {
+            let (l, r) = s.split_at_mut(two);
+            (&mut l[one], &mut r[0])
+        } else {
+            let (l, r) = s.split_at_mut(one);
+            (&mut r[0], &mut l[two])
+        }
+    }
+
+    impl Move {
+        pub fn apply_one_by_one(self, stacks: &mut [CrateStack]) {
+            let (f, t) = borrow_at_mut(stacks, self.from as usize - 1, self.to as usize - 1);
+            t.extend(f.drain(f.len().saturating_sub(self.count as usize)..).rev());
+        }
+
+        pub fn apply_batch(self, stacks: &mut [CrateStack]) {
+            let (f, t) = borrow_at_mut(stacks, self.from as usize - 1, self.to as usize - 1);
+            t.extend(f.drain(f.len().saturating_sub(self.count as usize)..));
+        }
+    }
+
+    fn moves(input: &str) -> IResult<&str, Vec<Move>> {
+        separated_list1(
+            line_ending,
+            map(
+                tuple((
+                    preceded(tag("move "), map_res(digit1, u32::from_str)),
+                    preceded(tag(" from "), map_res(digit1, u32::from_str)),
+                    preceded(tag(" to "), map_res(digit1, u32::from_str)),
+                )),
+                |(count, from, to)| Move { count, from, to },
+            ),
+        )(input)
+    }
+
+    pub fn parse_input(input: &str) -> IResult<&str, (Vec<Vec<Crate>>, Vec<Move>)> {
+        let (input, stacks) = stacks(input)?;
+        let (input, _) =
+            delimited(multispace1, separated_list1(space1, digit1), multispace1)(input)?;
+        let (input, moves) = terminated(moves, multispace0)(input)?;
+        Ok((input, (stacks, moves)))
+    }
+}
+
+fn top_crates(stacks: &[CrateStack]) -> String {
+    stacks
+        .iter()
+        .filter_map(|v| v.last().copied().map(Crate::as_char))
+        .collect()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 5;
+
+    type Parsed = (Vec<CrateStack>, Vec<Move>);
+    type Prepared = Self::Parsed;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        Ok(aoc_runner::parse::complete(parse_input, input)?)
+    }
+
+    fn prepare(data: Self::Parsed) -> Result<Self::Prepared> {
+        Ok(data)
+    }
+
+    fn part1((stacks, moves): &Self::Prepared) -> Result<Self::Answer1> {
+        let mut stacks = stacks.clone();
+        for ele in moves.iter() {
+            ele.apply_one_by_one(&mut stacks);
+        }
+        Ok(top_crates(&stacks))
+    }
+
+    fn part2((stacks, moves): &Self::Prepared) -> Result<Self::Answer2> {
+        let mut stacks = stacks.clone();
+        for ele in moves.iter() {
+            ele.apply_batch(&mut stacks);
+        }
+        Ok(top_crates(&stacks))
+    }
+}
+
+aoc_runner::examples! {
+    Day05,
+    "test_input" => { part1: "CMZ", part2: "MCD" },
+}
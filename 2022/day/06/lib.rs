@@ -0,0 +1,106 @@
+use aoc_runner::Solution;
+use color_eyre::{Report, Result};
+
+#[allow(dead_code)]
+fn empty_option_err() -> Report {
+    use std::io::{Error, ErrorKind::Other};
+    Error::new(Other, "Option was empty").into()
+}
+
+fn is_uniq<T: PartialEq>(s: &[T]) -> bool {
+    for (ix, x) in s.iter().enumerate() {
+        if s[ix + 1..].contains(x) {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn packet_start(input: &str) -> usize {
+    const PREV_SIZE: usize = 3;
+    let mut prev = [' '; PREV_SIZE];
+    prev.copy_from_slice(&input.chars().collect::<Vec<_>>()[..PREV_SIZE]);
+    for (ix, c) in input.chars().skip(PREV_SIZE).enumerate() {
+        if !prev.contains(&c) && is_uniq(&prev) {
+            return ix + PREV_SIZE + 1;
+        } else {
+            prev[ix % PREV_SIZE] = c;
+        }
+    }
+
+    unreachable!("Input contains no start marker")
+}
+
+fn message_start(input: &str) -> usize {
+    const PREV_SIZE: usize = 13;
+    let mut prev = [' '; PREV_SIZE];
+    prev.copy_from_slice(&input.chars().collect::<Vec<_>>()[..PREV_SIZE]);
+    for (ix, c) in input.chars().skip(PREV_SIZE).enumerate() {
+        if !prev.contains(&c) && is_uniq(&prev) {
+            return ix + PREV_SIZE + 1;
+        } else {
+            prev[ix % PREV_SIZE] = c;
+        }
+    }
+
+    unreachable!("Input contains no message marker")
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 6;
+
+    type Parsed = &'static str;
+    type Prepared = &'static str;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        Ok(input.trim_end())
+    }
+
+    fn prepare(datastream: Self::Parsed) -> Result<Self::Prepared> {
+        Ok(datastream)
+    }
+
+    fn part1(datastream: &Self::Prepared) -> Result<Self::Answer1> {
+        Ok(packet_start(datastream))
+    }
+
+    fn part2(datastream: &Self::Prepared) -> Result<Self::Answer2> {
+        Ok(message_start(datastream))
+    }
+}
+
+aoc_runner::examples! {
+    Day06,
+    "test_input" => { part1: 7, part2: 19 },
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{message_start, packet_start};
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn problem_1() {
+        assert_eq!(packet_start("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 7);
+        assert_eq!(packet_start("bvwbjplbgvbhsrlpgdmjqwftvncz"), 5);
+        assert_eq!(packet_start("nppdvjthqldpwncqszvftbrmjlhg"), 6);
+        assert_eq!(packet_start("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 10);
+        assert_eq!(packet_start("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 11);
+    }
+
+    #[test]
+    fn problem_2() {
+        assert_eq!(message_start("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 19);
+        assert_eq!(message_start("bvwbjplbgvbhsrlpgdmjqwftvncz"), 23);
+        assert_eq!(message_start("nppdvjthqldpwncqszvftbrmjlhg"), 23);
+        assert_eq!(message_start("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 29);
+        assert_eq!(message_start("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 26);
+    }
+}
@@ -0,0 +1,310 @@
+use std::fmt::Debug;
+
+use aoc_runner::Solution;
+use color_eyre::{Report, Result};
+use parse::Command;
+
+#[allow(dead_code)]
+fn empty_option_err() -> Report {
+    use std::io::{Error, ErrorKind::Other};
+    Error::new(Other, "Option was empty").into()
+}
+
+const MAX_DIR_SIZE: u32 = 100_000;
+const DISK_SIZE: u32 = 70_000_000;
+const REQUIRED_SPACE: u32 = 30_000_000;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 7;
+
+    type Parsed = Vec<Command<'static>>;
+    type Prepared = Dir<'static>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        Ok(aoc_runner::parse::complete(parse::input, input)?)
+    }
+
+    fn prepare(cmds: Self::Parsed) -> Result<Self::Prepared> {
+        Ok(Dir::from(cmds.as_slice()))
+    }
+
+    fn part1(filesystem: &Self::Prepared) -> Result<Self::Answer1> {
+        Ok(filesystem_size(filesystem, MAX_DIR_SIZE))
+    }
+
+    fn part2(filesystem: &Self::Prepared) -> Result<Self::Answer2> {
+        Ok(free_space(filesystem, REQUIRED_SPACE, DISK_SIZE))
+    }
+}
+
+fn free_space<'a, 'b: 'a>(fs: &'a Dir<'b>, required: u32, total: u32) -> u32 {
+    let unused = total - fs.calculate_size();
+    let to_free = required - unused;
+
+    let mut queue: Vec<_> = fs
+        .files
+        .iter()
+        .filter_map(|entry| match &entry.kind {
+            EntryKind::Dir(dir) => Some(dir),
+            EntryKind::File(_) => None,
+        })
+        .collect();
+
+    let mut min_size = fs.calculate_size();
+
+    while let Some(wd) = queue.pop() {
+        let size = wd.calculate_size();
+        if size < min_size && size >= to_free {
+            min_size = size;
+        }
+
+        for file in &wd.files {
+            match &file.kind {
+                EntryKind::Dir(dir) => queue.push(dir),
+                _ => continue,
+            }
+        }
+    }
+
+    min_size
+}
+
+fn filesystem_size(filesystem: &Dir, max_dir_size: u32) -> u32 {
+    let mut size = 0;
+
+    let mut queue = vec![filesystem];
+    while let Some(wd) = queue.pop() {
+        if wd.calculate_size() <= max_dir_size {
+            size += wd.calculate_size();
+        }
+
+        for file in &wd.files {
+            match &file.kind {
+                EntryKind::Dir(dir) => queue.push(dir),
+                _ => continue,
+            }
+        }
+    }
+
+    size
+}
+
+struct DirEntry<'a> {
+    name: &'a str,
+    kind: EntryKind<'a>,
+}
+
+#[derive(Debug)]
+pub struct Dir<'a> {
+    files: Vec<DirEntry<'a>>,
+}
+
+#[derive(Debug)]
+struct File {
+    size: u32,
+}
+
+#[derive(Debug)]
+enum EntryKind<'a> {
+    File(File),
+    Dir(Dir<'a>),
+}
+
+impl Debug for DirEntry<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ", self.name)?;
+        match &self.kind {
+            EntryKind::Dir(Dir { files }) => f.debug_list().entries(files).finish(),
+            EntryKind::File(File { size }) => write!(f, "{size}"),
+        }
+    }
+}
+
+impl Dir<'_> {
+    fn calculate_size(&self) -> u32 {
+        self.files.iter().map(DirEntry::calculate_size).sum()
+    }
+}
+
+impl DirEntry<'_> {
+    fn calculate_size(&self) -> u32 {
+        match &self.kind {
+            EntryKind::Dir(dir) => dir.calculate_size(),
+            EntryKind::File(File { size }) => *size,
+        }
+    }
+}
+
+impl<'a> Dir<'a> {
+    fn lookup_dir_mut(&mut self, path: &[&str]) -> &mut Dir<'a> {
+        match path {
+            [] => self,
+            [name] => {
+                let entry = self
+                    .files
+                    .iter_mut()
+                    .find(|child| &child.name == name)
+                    .expect("dir not found");
+                match &mut entry.kind {
+                    EntryKind::File(_) => panic!("Expected Dir"),
+                    EntryKind::Dir(dir) => dir,
+                }
+            }
+            _ => self.lookup_dir_mut(&[path[0]]).lookup_dir_mut(&path[1..]),
+        }
+    }
+}
+
+impl<'a> From<&[Command<'a>]> for Dir<'a> {
+    fn from(cmds: &[Command<'a>]) -> Self {
+        use Command::*;
+
+        let mut root = Self { files: vec![] };
+        let mut pwd = vec![];
+
+        for cmd in cmds {
+            match cmd {
+                Cd(parse::Dir::Root) => pwd.clear(),
+                Cd(parse::Dir::Up) => {
+                    pwd.pop();
+                }
+                Cd(parse::Dir::Name(name)) => pwd.push(*name),
+                Ls(files) => {
+                    let wd = root.lookup_dir_mut(&pwd);
+
+                    assert!(wd.files.is_empty());
+                    for file in files.iter().copied() {
+                        wd.files.push(file.into())
+                    }
+                }
+            };
+        }
+
+        root
+    }
+}
+
+impl<'a> From<parse::DirEntry<'a>> for DirEntry<'a> {
+    fn from(de: parse::DirEntry<'a>) -> Self {
+        match de {
+            parse::DirEntry::File { size, name } => Self {
+                name,
+                kind: EntryKind::File(File { size }),
+            },
+            parse::DirEntry::Dir { name } => Self {
+                name,
+                kind: EntryKind::Dir(Dir { files: vec![] }),
+            },
+        }
+    }
+}
+
+mod parse {
+    use aoc_runner::parse::IResult;
+    use nom::{
+        branch::alt,
+        character::{
+            complete::{digit1, line_ending, space1},
+            streaming::not_line_ending,
+        },
+        combinator::{map, map_res},
+        multi::{many0, many1},
+        sequence::{delimited, pair, preceded, separated_pair, terminated},
+    };
+    use nom_supreme::tag::complete::tag;
+
+    #[derive(Debug)]
+    pub enum Command<'a> {
+        Cd(Dir<'a>),
+        Ls(Vec<DirEntry<'a>>),
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    pub enum Dir<'a> {
+        Root,
+        Up,
+        Name(&'a str),
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    pub enum DirEntry<'a> {
+        File { size: u32, name: &'a str },
+        Dir { name: &'a str },
+    }
+
+    pub fn input(input: &str) -> IResult<&str, Vec<Command<'_>>> {
+        many1(command)(input)
+    }
+
+    fn command(input: &str) -> IResult<&str, Command<'_>> {
+        alt((cd_cmd, ls_cmd))(input)
+    }
+
+    fn cd_cmd(input: &str) -> IResult<&str, Command<'_>> {
+        use Command::Cd;
+        use Dir::{Name, Root, Up};
+        map(
+            delimited(tag("$ cd "), not_line_ending, line_ending),
+            |s| match s {
+                ".." => Cd(Up),
+                "/" => Cd(Root),
+                _ => Cd(Name(s)),
+            },
+        )(input)
+    }
+
+    fn ls_cmd(input: &str) -> IResult<&str, Command<'_>> {
+        use Command::Ls;
+        use DirEntry::{Dir, File};
+
+        preceded(
+            pair(tag("$ ls"), line_ending),
+            map(
+                many0(terminated(
+                    alt((
+                        map(
+                            separated_pair(
+                                map_res(digit1, str::parse::<u32>),
+                                space1,
+                                not_line_ending,
+                            ),
+                            |(size, name)| File { size, name },
+                        ),
+                        map(preceded(tag("dir "), not_line_ending), |name| Dir { name }),
+                    )),
+                    line_ending,
+                )),
+                Ls,
+            ),
+        )(input)
+    }
+}
+
+aoc_runner::examples! {
+    Day07,
+    "test_input" => { part1: 95437, part2: 24933642 },
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{parse, Dir};
+    use color_eyre::Result;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    static INPUT: &str = include_str!("test_input");
+
+    #[test]
+    fn total_size() -> Result<()> {
+        let (_, test_cmds) = parse::input(INPUT)?;
+        let test_fs = Dir::from(test_cmds.as_slice());
+        assert_eq!(test_fs.calculate_size(), 48381165);
+
+        Ok(())
+    }
+}
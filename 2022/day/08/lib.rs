@@ -0,0 +1,84 @@
+use aoc_runner::Solution;
+use aoc_utils::grid::{Grid, Pos};
+use color_eyre::{Report, Result};
+
+#[allow(dead_code)]
+fn empty_option_err() -> Report {
+    use std::io::{Error, ErrorKind::Other};
+    Error::new(Other, "Option was empty").into()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 8;
+
+    type Parsed = Grid<u8>;
+    type Prepared = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        Ok(Grid::parse_chars(input, |c| {
+            c.to_digit(10).map(|h| h as u8).ok_or(InvalidHeight(c))
+        })?)
+    }
+
+    fn prepare(forest: Self::Parsed) -> Result<Self::Prepared> {
+        Ok(forest)
+    }
+
+    fn part1(forest: &Self::Prepared) -> Result<Self::Answer1> {
+        Ok(visible_trees(forest))
+    }
+
+    fn part2(forest: &Self::Prepared) -> Result<Self::Answer2> {
+        Ok(best_scenic_score(forest))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("invalid tree height {0:?}")]
+pub struct InvalidHeight(char);
+
+fn best_scenic_score(forest: &Grid<u8>) -> usize {
+    forest
+        .positions()
+        .map(|pos| scenic_score(forest, pos))
+        .max()
+        .unwrap_or(0)
+}
+
+fn scenic_score(forest: &Grid<u8>, tree: Pos) -> usize {
+    let height = forest[tree];
+    Pos::directions4()
+        .into_iter()
+        .map(|dir| {
+            let mut count = 0;
+            for (_, &other) in forest.ray(tree, dir) {
+                count += 1;
+                if other >= height {
+                    break;
+                }
+            }
+            count
+        })
+        .product()
+}
+
+fn visible_trees(forest: &Grid<u8>) -> usize {
+    forest
+        .iter()
+        .filter(|&(pos, height)| {
+            Pos::directions4()
+                .into_iter()
+                .any(|dir| forest.ray(pos, dir).all(|(_, tree)| tree < height))
+        })
+        .count()
+}
+
+aoc_runner::examples! {
+    Day08,
+    "test_input" => { part1: 21, part2: 8 },
+}
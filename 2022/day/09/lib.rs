@@ -0,0 +1,298 @@
+use std::{
+    collections::HashSet,
+    iter::{once, repeat_n},
+};
+
+use aoc_runner::Solution;
+use aoc_utils::vector::Vec2;
+use color_eyre::{Report, Result};
+pub use parse::{Direction, Move};
+
+#[allow(dead_code)]
+fn empty_option_err() -> Report {
+    use std::io::{Error, ErrorKind::Other};
+    Error::new(Other, "Option was empty").into()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 9;
+
+    type Parsed = Vec<Move>;
+    type Prepared = Vec<Move>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        Ok(aoc_runner::parse::complete(parse::input, input)?)
+    }
+
+    fn prepare(moves: Self::Parsed) -> Result<Self::Prepared> {
+        Ok(moves)
+    }
+
+    fn part1(moves: &Self::Prepared) -> Result<Self::Answer1> {
+        Ok(visited_positions(moves, 2).len())
+    }
+
+    fn part2(moves: &Self::Prepared) -> Result<Self::Answer2> {
+        Ok(visited_positions(moves, 10).len())
+    }
+}
+
+/// A rope of knots, each following the previous one, the first knot is the head
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rope {
+    knots: Vec<Vec2<i32>>,
+}
+
+impl Rope {
+    /// A rope with all its `knots` at the origin, the start
+    ///
+    /// # Panics
+    ///
+    /// If the rope has no knots.
+    pub fn new(knots: usize) -> Self {
+        assert!(knots > 0, "a rope needs at least one knot");
+        Self {
+            knots: vec![Vec2::default(); knots],
+        }
+    }
+
+    pub fn knots(&self) -> &[Vec2<i32>] {
+        &self.knots
+    }
+
+    pub fn head(&self) -> Vec2<i32> {
+        self.knots[0]
+    }
+
+    pub fn tail(&self) -> Vec2<i32> {
+        self.knots[self.knots.len() - 1]
+    }
+
+    /// Move the head one step in `direction` and let the other knots follow it
+    pub fn step(&mut self, direction: Direction) {
+        self.knots[0] += direction.step();
+        for ix in 1..self.knots.len() {
+            self.knots[ix] = move_towards(self.knots[ix], self.knots[ix - 1]);
+        }
+    }
+
+    /// The state of the rope after each step of the `moves`
+    pub fn simulate(self, moves: &[Move]) -> impl Iterator<Item = Rope> + '_ {
+        moves
+            .iter()
+            .flat_map(|move_| repeat_n(move_.direction, move_.amount as usize))
+            .scan(self, |rope, direction| {
+                rope.step(direction);
+                Some(rope.clone())
+            })
+    }
+
+    /// The label of the knot at `ix` in the diagrams of the puzzle
+    fn label(&self, ix: usize) -> char {
+        match ix {
+            0 => 'H',
+            1 if self.knots.len() == 2 => 'T',
+            ix => char::from_digit(ix as u32 % 10, 10).expect("a single digit"),
+        }
+    }
+
+    /// Draw the rope like the diagrams of the puzzle, within `min..=max`
+    ///
+    /// `y` goes up, knots closer to the head are drawn over the others and `s` marks the
+    /// start if there is no knot on it.
+    pub fn render(&self, min: Vec2<i32>, max: Vec2<i32>) -> String {
+        let mut frame = String::new();
+        for y in (min.y..=max.y).rev() {
+            for x in min.x..=max.x {
+                let pos = Vec2::new(x, y);
+                let cell = match self.knots.iter().position(|&knot| knot == pos) {
+                    Some(ix) => self.label(ix),
+                    None if pos == Vec2::default() => 's',
+                    None => '.',
+                };
+                frame.push(cell);
+            }
+            frame.push('\n');
+        }
+        frame
+    }
+}
+
+/// The positions visited by the tail of a rope with `knots` following the `moves`
+pub fn visited_positions(moves: &[Move], knots: usize) -> HashSet<Vec2<i32>> {
+    let rope = Rope::new(knots);
+    once(rope.tail())
+        .chain(rope.simulate(moves).map(|rope| rope.tail()))
+        .collect()
+}
+
+fn move_towards(this: Vec2<i32>, that: Vec2<i32>) -> Vec2<i32> {
+    if this.chebyshev(that) <= 1 {
+        this
+    } else {
+        this + (that - this).signum()
+    }
+}
+
+mod parse {
+    use aoc_runner::parse::IResult;
+    use aoc_utils::vector::Vec2;
+    use nom::{
+        character::complete::{digit1, line_ending, one_of, space1},
+        combinator::{map, map_res},
+        multi::many1,
+        sequence::{separated_pair, terminated},
+    };
+
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    pub enum Direction {
+        Up,
+        Down,
+        Left,
+        Right,
+    }
+
+    impl Direction {
+        /// The move of one step in this direction, up is towards positive `y`
+        pub fn step(self) -> Vec2<i32> {
+            match self {
+                Direction::Up => Vec2::new(0, 1),
+                Direction::Down => Vec2::new(0, -1),
+                Direction::Left => Vec2::new(-1, 0),
+                Direction::Right => Vec2::new(1, 0),
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct Move {
+        pub amount: i32,
+        pub direction: Direction,
+    }
+
+    pub fn input(input: &str) -> IResult<&str, Vec<Move>> {
+        many1(terminated(
+            map(
+                separated_pair(
+                    map(one_of("UDLR"), |c| match c {
+                        'U' => Direction::Up,
+                        'D' => Direction::Down,
+                        'L' => Direction::Left,
+                        'R' => Direction::Right,
+                        _ => unreachable!("{c} is not a valid side"),
+                    }),
+                    space1,
+                    map_res(digit1, str::parse),
+                ),
+                |(direction, amount): (_, i32)| Move { direction, amount },
+            ),
+            line_ending,
+        ))(input)
+    }
+}
+
+aoc_runner::examples! {
+    Day09,
+    "test_input" => { part1: 13, part2: 1 },
+    "test_input2" => { part2: 36 },
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{parse, Rope};
+    use aoc_utils::vector::Vec2;
+    use color_eyre::Result;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    static INPUT: &str = include_str!("test_input");
+
+    fn frame() -> (Vec2<i32>, Vec2<i32>) {
+        (Vec2::new(0, 0), Vec2::new(5, 4))
+    }
+
+    #[test]
+    fn short_rope() -> Result<()> {
+        let moves = parse::input(INPUT)?.1;
+        let (min, max) = frame();
+
+        let states: Vec<_> = Rope::new(2).simulate(&moves).collect();
+        assert_eq!(states.len(), 24);
+        assert_eq!(
+            states[3].render(min, max),
+            "\
+......
+......
+......
+......
+s..TH.
+"
+        );
+        assert_eq!(
+            states[7].render(min, max),
+            "\
+....H.
+....T.
+......
+......
+s.....
+"
+        );
+        assert_eq!(
+            states.last().unwrap().render(min, max),
+            "\
+......
+......
+.TH...
+......
+s.....
+"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn long_rope() -> Result<()> {
+        let moves = parse::input(INPUT)?.1;
+        let (min, max) = frame();
+
+        let states: Vec<_> = Rope::new(10).simulate(&moves).collect();
+        assert_eq!(
+            states[3].render(min, max),
+            "\
+......
+......
+......
+......
+4321H.
+"
+        );
+        assert_eq!(
+            states[7].render(min, max),
+            "\
+....H.
+....1.
+..432.
+.5....
+6.....
+"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn single_knot() {
+        let mut rope = Rope::new(1);
+        rope.step(parse::Direction::Left);
+
+        assert_eq!(rope.head(), rope.tail());
+        assert_eq!(rope.knots(), [Vec2::new(-1, 0)]);
+    }
+}
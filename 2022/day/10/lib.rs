@@ -0,0 +1,194 @@
+use aoc_runner::Solution;
+use aoc_utils::ocr::{self, SMALL};
+use color_eyre::{Report, Result};
+use vm::{Cpu, Cycle, Observer, Op, X};
+
+pub mod vm;
+
+#[allow(dead_code)]
+fn empty_option_err() -> Report {
+    use std::io::{Error, ErrorKind::Other};
+    Error::new(Other, "Option was empty").into()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 10;
+
+    type Parsed = Vec<Op>;
+    type Prepared = Vec<Op>;
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        Ok(aoc_runner::parse::complete(parse::input, input)?)
+    }
+
+    fn prepare(program: Self::Parsed) -> Result<Self::Prepared> {
+        Ok(program)
+    }
+
+    fn part1(program: &Self::Prepared) -> Result<Self::Answer1> {
+        Ok(signal_strength_sum::<20, 40>(program))
+    }
+
+    fn part2(program: &Self::Prepared) -> Result<Self::Answer2> {
+        Ok(ocr::read(&crt_drawing::<40, 6>(program), &SMALL)?)
+    }
+}
+
+pub type Program<'a> = &'a [Op];
+
+fn signal_strength_sum<const START: u32, const STEP: u32>(program: Program) -> i32 {
+    let mut signal = SignalStrength::new(START, STEP);
+    Cpu::new(program).observe(&mut signal).run();
+    signal.sum
+}
+
+fn crt_drawing<const CRT_WIDTH: u32, const CRT_HEIGHT: u32>(program: Program) -> String {
+    let mut crt = Crt::new(CRT_WIDTH, CRT_HEIGHT);
+    Cpu::new(program).observe(&mut crt).run();
+    crt.screen
+}
+
+/// Sums the signal strength during the cycles `start`, `start + step`, `start + 2 * step`...
+#[derive(Debug, Clone)]
+pub struct SignalStrength {
+    start: u32,
+    step: u32,
+    pub sum: i32,
+}
+
+impl SignalStrength {
+    pub fn new(start: u32, step: u32) -> Self {
+        Self {
+            start,
+            step,
+            sum: 0,
+        }
+    }
+}
+
+impl<I> Observer<I> for SignalStrength {
+    fn on_cycle(&mut self, cycle: &Cycle<I>) {
+        if cycle.number >= self.start && (cycle.number - self.start).is_multiple_of(self.step) {
+            self.sum += cycle.number as i32 * cycle.registers.get(X);
+        }
+    }
+}
+
+/// Draws a pixel each cycle, lit if the 3 pixels wide sprite centered at `x` covers it
+#[derive(Debug, Clone)]
+pub struct Crt {
+    width: u32,
+    height: u32,
+    /// The rows drawn so far, each ending in a newline
+    pub screen: String,
+}
+
+impl Crt {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            screen: String::new(),
+        }
+    }
+}
+
+impl<I> Observer<I> for Crt {
+    fn on_cycle(&mut self, cycle: &Cycle<I>) {
+        // The beam goes back to the top once the screen is full
+        let pixel = (cycle.number - 1) % (self.width * self.height);
+        if pixel == 0 {
+            self.screen.clear();
+        }
+
+        let column = pixel % self.width;
+        let sprite = cycle.registers.get(X);
+        self.screen
+            .push(if (-1..=1).contains(&(sprite - column as i32)) {
+                '#'
+            } else {
+                '.'
+            });
+        if column == self.width - 1 {
+            self.screen.push('\n');
+        }
+    }
+}
+
+mod parse {
+    use aoc_runner::parse::IResult;
+    use nom::{
+        branch::alt,
+        character::complete::{anychar, char, i32, line_ending},
+        combinator::{map, map_opt},
+        multi::many1,
+        sequence::{preceded, separated_pair, terminated},
+    };
+    use nom_supreme::tag::complete::tag;
+
+    use crate::vm::{Op, Register};
+
+    fn register(input: &str) -> IResult<&str, Register> {
+        map_opt(anychar, Register::new)(input)
+    }
+
+    pub fn input(input: &str) -> IResult<&str, Vec<Op>> {
+        many1(terminated(
+            alt((
+                map(tag("noop"), |_| Op::Noop),
+                map(
+                    preceded(tag("add"), separated_pair(register, char(' '), i32)),
+                    |(register, value)| Op::Add(register, value),
+                ),
+            )),
+            line_ending,
+        ))(input)
+    }
+}
+
+aoc_runner::examples! {
+    Day10,
+    "test_input" => { part1: 13140 },
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        crt_drawing,
+        vm::{Op, X},
+    };
+    use color_eyre::Result;
+    use pretty_assertions::assert_eq;
+
+    static INPUT: &str = "noop\naddx 3\naddx -5\n";
+    static TEST_INPUT: &str = include_str!("test_input");
+    static TEST_IMAGE: &str = "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+";
+
+    #[test]
+    fn problem_1_parse_input() -> Result<()> {
+        assert_eq!(
+            &super::parse::input(INPUT)?.1,
+            &[Op::Noop, Op::Add(X, 3), Op::Add(X, -5)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn problem_2() -> Result<()> {
+        let program = super::parse::input(TEST_INPUT)?.1;
+        assert_eq!(&crt_drawing::<40, 6>(&program), TEST_IMAGE);
+        Ok(())
+    }
+}
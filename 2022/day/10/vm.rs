@@ -0,0 +1,407 @@
+//! A register machine running one instruction after the other
+//!
+//! Instructions take a number of cycles and only change the registers at the end of their
+//! last cycle. [`Observer`]s see the state of the machine during every cycle, and
+//! [`Breakpoint`]s pause it before a cycle runs.
+use std::{
+    collections::BTreeMap,
+    fmt::{Debug, Display},
+};
+
+/// A register of the machine, named by a lowercase letter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Register(char);
+
+/// The only register used by the puzzle
+pub const X: Register = Register('x');
+
+impl Register {
+    pub fn new(name: char) -> Option<Self> {
+        name.is_ascii_lowercase().then_some(Self(name))
+    }
+
+    pub fn name(self) -> char {
+        self.0
+    }
+}
+
+impl Display for Register {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The values of the registers, the ones never written are `0`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Registers {
+    values: BTreeMap<Register, i32>,
+}
+
+impl Registers {
+    pub fn get(&self, register: Register) -> i32 {
+        self.values.get(&register).copied().unwrap_or(0)
+    }
+
+    pub fn set(&mut self, register: Register, value: i32) {
+        self.values.insert(register, value);
+    }
+}
+
+impl Display for Registers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (ix, (register, value)) in self.values.iter().enumerate() {
+            if ix > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{register}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// An instruction of the machine, its [`Display`] is its assembly
+pub trait Instruction: Display {
+    /// Cycles it takes to run, at least one
+    fn cycles(&self) -> u32;
+
+    /// Apply the effects of the instruction, at the end of its last cycle
+    fn execute(&self, registers: &mut Registers);
+}
+
+impl<I: Instruction + ?Sized> Instruction for Box<I> {
+    fn cycles(&self) -> u32 {
+        (**self).cycles()
+    }
+
+    fn execute(&self, registers: &mut Registers) {
+        (**self).execute(registers)
+    }
+}
+
+/// The instructions of the puzzle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Noop,
+    /// Add a value to a register, `addx 3` adds `3` to `x`
+    Add(Register, i32),
+}
+
+impl Instruction for Op {
+    fn cycles(&self) -> u32 {
+        match self {
+            Op::Noop => 1,
+            Op::Add(..) => 2,
+        }
+    }
+
+    fn execute(&self, registers: &mut Registers) {
+        match *self {
+            Op::Noop => {}
+            Op::Add(register, value) => registers.set(register, registers.get(register) + value),
+        }
+    }
+}
+
+impl Display for Op {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Op::Noop => write!(f, "noop"),
+            Op::Add(register, value) => write!(f, "add{register} {value}"),
+        }
+    }
+}
+
+/// The state of the machine during a cycle
+#[derive(Debug)]
+pub struct Cycle<'a, I> {
+    /// Starting from `1`
+    pub number: u32,
+    /// Index of the instruction running
+    pub pc: usize,
+    pub instruction: &'a I,
+    /// The registers before the instruction finishes
+    pub registers: &'a Registers,
+}
+
+/// Something looking at every cycle of the machine
+pub trait Observer<I> {
+    fn on_cycle(&mut self, cycle: &Cycle<I>);
+}
+
+impl<I, F: FnMut(&Cycle<I>)> Observer<I> for F {
+    fn on_cycle(&mut self, cycle: &Cycle<I>) {
+        self(cycle)
+    }
+}
+
+/// Where to pause the machine, before the cycle runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Breakpoint {
+    /// Before the cycle with this number
+    Cycle(u32),
+    /// Before any cycle where the register has this value
+    Register(Register, i32),
+}
+
+/// Why the machine stopped running
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    /// The program is over
+    Halted,
+    Breakpoint(Breakpoint),
+}
+
+pub struct Cpu<'a, I> {
+    program: &'a [I],
+    pc: usize,
+    /// Cycles already spent on the current instruction
+    progress: u32,
+    /// Cycles completed
+    cycle: u32,
+    registers: Registers,
+    observers: Vec<&'a mut dyn Observer<I>>,
+    breakpoints: Vec<Breakpoint>,
+    /// Don't stop at a breakpoint before running a cycle, to resume after one
+    resuming: bool,
+}
+
+impl<'a, I: Instruction> Cpu<'a, I> {
+    /// A machine about to run `program`, with `x` set to `1`
+    pub fn new(program: &'a [I]) -> Self {
+        let mut registers = Registers::default();
+        registers.set(X, 1);
+
+        Self {
+            program,
+            pc: 0,
+            progress: 0,
+            cycle: 0,
+            registers,
+            observers: vec![],
+            breakpoints: vec![],
+            resuming: false,
+        }
+    }
+
+    /// Call `observer` during every cycle
+    pub fn observe(mut self, observer: &'a mut dyn Observer<I>) -> Self {
+        self.observers.push(observer);
+        self
+    }
+
+    pub fn break_on(mut self, breakpoint: Breakpoint) -> Self {
+        self.breakpoints.push(breakpoint);
+        self
+    }
+
+    /// Number of cycles completed
+    pub fn cycle(&self) -> u32 {
+        self.cycle
+    }
+
+    /// Index of the next instruction to run, or to finish running
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    pub fn registers(&self) -> &Registers {
+        &self.registers
+    }
+
+    pub fn halted(&self) -> bool {
+        self.pc >= self.program.len()
+    }
+
+    /// Run until the program is over or a breakpoint is hit, running again resumes it
+    pub fn run(&mut self) -> Stop {
+        loop {
+            if self.halted() {
+                return Stop::Halted;
+            }
+            if !std::mem::take(&mut self.resuming) {
+                if let Some(&breakpoint) = self.breakpoints.iter().find(|bp| self.hit(bp)) {
+                    self.resuming = true;
+                    return Stop::Breakpoint(breakpoint);
+                }
+            }
+            self.step();
+        }
+    }
+
+    /// Run a single cycle, `false` if the program is over
+    pub fn step(&mut self) -> bool {
+        let Some(instruction) = self.program.get(self.pc) else {
+            return false;
+        };
+
+        self.cycle += 1;
+        let cycle = Cycle {
+            number: self.cycle,
+            pc: self.pc,
+            instruction,
+            registers: &self.registers,
+        };
+        for observer in &mut self.observers {
+            observer.on_cycle(&cycle);
+        }
+
+        self.progress += 1;
+        if self.progress >= instruction.cycles() {
+            instruction.execute(&mut self.registers);
+            self.pc += 1;
+            self.progress = 0;
+        }
+        self.resuming = false;
+
+        true
+    }
+
+    fn hit(&self, breakpoint: &Breakpoint) -> bool {
+        match *breakpoint {
+            Breakpoint::Cycle(cycle) => self.cycle + 1 == cycle,
+            Breakpoint::Register(register, value) => self.registers.get(register) == value,
+        }
+    }
+}
+
+/// Records every cycle, to print them all after running
+#[derive(Debug, Clone, Default)]
+pub struct Trace {
+    pub entries: Vec<TraceEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceEntry {
+    pub cycle: u32,
+    pub pc: usize,
+    pub instruction: String,
+    pub registers: Registers,
+}
+
+impl<I: Instruction> Observer<I> for Trace {
+    fn on_cycle(&mut self, cycle: &Cycle<I>) {
+        self.entries.push(TraceEntry {
+            cycle: cycle.number,
+            pc: cycle.pc,
+            instruction: cycle.instruction.to_string(),
+            registers: cycle.registers.clone(),
+        });
+    }
+}
+
+impl Display for Trace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for entry in &self.entries {
+            writeln!(
+                f,
+                "{:>5} {:>4}  {:<12} {}",
+                entry.cycle, entry.pc, entry.instruction, entry.registers
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The assembly of `program`, with the index of each instruction and the cycle it starts on
+pub fn disassemble<I: Instruction>(program: &[I]) -> String {
+    let mut cycle = 1;
+    let mut listing = String::new();
+    for (pc, instruction) in program.iter().enumerate() {
+        listing += &format!("{pc:>4} {cycle:>5}  {instruction}\n");
+        cycle += instruction.cycles();
+    }
+    listing
+}
+
+#[cfg(test)]
+mod test {
+    use crate::vm::{
+        disassemble, Breakpoint, Cpu, Cycle, Instruction, Op, Register, Registers, Stop, Trace, X,
+    };
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    const PROGRAM: [Op; 3] = [Op::Noop, Op::Add(X, 3), Op::Add(X, -5)];
+
+    #[test]
+    fn run_to_the_end() {
+        let mut xs = vec![];
+        let mut record = |cycle: &Cycle<Op>| xs.push((cycle.number, cycle.registers.get(X)));
+
+        let mut cpu = Cpu::new(&PROGRAM).observe(&mut record);
+        assert_eq!(cpu.run(), Stop::Halted);
+        assert_eq!(cpu.cycle(), 5);
+        assert_eq!(cpu.registers().get(X), -1);
+        drop(cpu);
+
+        assert_eq!(xs, [(1, 1), (2, 1), (3, 1), (4, 4), (5, 4)]);
+    }
+
+    #[test]
+    fn breakpoints() {
+        let mut cpu = Cpu::new(&PROGRAM)
+            .break_on(Breakpoint::Cycle(3))
+            .break_on(Breakpoint::Register(X, 4));
+
+        assert_eq!(cpu.run(), Stop::Breakpoint(Breakpoint::Cycle(3)));
+        assert_eq!((cpu.cycle(), cpu.pc()), (2, 1));
+
+        assert_eq!(cpu.run(), Stop::Breakpoint(Breakpoint::Register(X, 4)));
+        assert_eq!((cpu.cycle(), cpu.pc()), (3, 2));
+
+        // Still 4 during the next cycle
+        assert_eq!(cpu.run(), Stop::Breakpoint(Breakpoint::Register(X, 4)));
+        assert_eq!(cpu.cycle(), 4);
+
+        assert_eq!(cpu.run(), Stop::Halted);
+        assert!(cpu.halted());
+    }
+
+    /// Multiply a register, slowly
+    struct Mul(Register, i32);
+
+    impl Instruction for Mul {
+        fn cycles(&self) -> u32 {
+            4
+        }
+
+        fn execute(&self, registers: &mut Registers) {
+            registers.set(self.0, registers.get(self.0) * self.1);
+        }
+    }
+
+    impl std::fmt::Display for Mul {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "mul{} {}", self.0, self.1)
+        }
+    }
+
+    #[test]
+    fn custom_instructions() {
+        let y = Register::new('y').unwrap();
+        let program: Vec<Box<dyn Instruction>> = vec![
+            Box::new(Op::Add(y, 2)),
+            Box::new(Mul(y, 7)),
+            Box::new(Mul(X, 3)),
+        ];
+
+        let mut trace = Trace::default();
+        let mut cpu = Cpu::new(&program).observe(&mut trace);
+        cpu.run();
+        assert_eq!(cpu.registers().to_string(), "x=3 y=14");
+        drop(cpu);
+
+        assert_eq!(trace.entries.len(), 10);
+        assert_eq!(trace.entries[3].instruction, "muly 7");
+        assert_eq!(trace.entries[6].registers.to_string(), "x=1 y=14");
+        assert_eq!(
+            trace.to_string().lines().nth(2),
+            Some("    3    1  muly 7       x=1 y=2")
+        );
+
+        assert_eq!(
+            disassemble(&program),
+            "   0     1  addy 2\n   1     3  muly 7\n   2     7  mulx 3\n"
+        );
+    }
+}
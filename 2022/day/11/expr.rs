@@ -0,0 +1,297 @@
+//! The expressions monkeys use to change worry levels and to test them
+//!
+//! An operation is an arithmetic expression of `old`, the worry level before inspecting
+//! the item, with `+ - * / %`, the usual precedence and parentheses. A test is one of
+//! `divisible by N`, `greater than N`, `less than N` or `equal to N`.
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Formatter},
+};
+
+use aoc_runner::parse::IResult;
+use nom::{
+    branch::alt,
+    character::complete::{char, one_of, space0, u64},
+    combinator::verify,
+    multi::fold_many0,
+    sequence::{delimited, pair, preceded},
+    Parser,
+};
+use nom_supreme::tag::complete::tag;
+
+use crate::worry::{Arithmetic, WorryError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    /// The worry level before inspecting the item
+    Old,
+    Num(u64),
+    Binary(Box<Expr>, BinOp, Box<Expr>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+impl BinOp {
+    fn from_symbol(symbol: char) -> Self {
+        match symbol {
+            '+' => BinOp::Add,
+            '-' => BinOp::Sub,
+            '*' => BinOp::Mul,
+            '/' => BinOp::Div,
+            '%' => BinOp::Rem,
+            _ => unreachable!("{symbol} is not an operator"),
+        }
+    }
+
+    fn symbol(self) -> char {
+        match self {
+            BinOp::Add => '+',
+            BinOp::Sub => '-',
+            BinOp::Mul => '*',
+            BinOp::Div => '/',
+            BinOp::Rem => '%',
+        }
+    }
+
+    /// Operators with a higher precedence are applied first
+    fn precedence(self) -> u8 {
+        match self {
+            BinOp::Add | BinOp::Sub => 1,
+            BinOp::Mul | BinOp::Div | BinOp::Rem => 2,
+        }
+    }
+}
+
+impl Expr {
+    pub fn binary(lhs: Expr, op: BinOp, rhs: Expr) -> Self {
+        Expr::Binary(Box::new(lhs), op, Box::new(rhs))
+    }
+
+    /// The new worry level when the old one is `old`
+    pub fn eval<A: Arithmetic>(
+        &self,
+        arithmetic: &A,
+        old: &A::Worry,
+    ) -> Result<A::Worry, WorryError> {
+        match self {
+            Expr::Old => Ok(old.clone()),
+            Expr::Num(n) => Ok(arithmetic.worry(*n)),
+            Expr::Binary(lhs, op, rhs) => {
+                let lhs = lhs.eval(arithmetic, old)?;
+                let rhs = rhs.eval(arithmetic, old)?;
+                match op {
+                    BinOp::Add => arithmetic.add(&lhs, &rhs),
+                    BinOp::Sub => arithmetic.sub(&lhs, &rhs),
+                    BinOp::Mul => arithmetic.mul(&lhs, &rhs),
+                    BinOp::Div => arithmetic.div(&lhs, &rhs),
+                    BinOp::Rem => arithmetic.rem(&lhs, &rhs),
+                }
+            }
+        }
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::Binary(_, op, _) => op.precedence(),
+            _ => u8::MAX,
+        }
+    }
+}
+
+/// Only the parentheses needed to parse it back are written
+impl Display for Expr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expr::Old => write!(f, "old"),
+            Expr::Num(n) => write!(f, "{n}"),
+            Expr::Binary(lhs, op, rhs) => {
+                // All the operators are left associative
+                if lhs.precedence() < op.precedence() {
+                    write!(f, "({lhs})")?;
+                } else {
+                    write!(f, "{lhs}")?;
+                }
+                write!(f, " {} ", op.symbol())?;
+                if rhs.precedence() <= op.precedence() {
+                    write!(f, "({rhs})")
+                } else {
+                    write!(f, "{rhs}")
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Condition {
+    DivisibleBy(u64),
+    GreaterThan(u64),
+    LessThan(u64),
+    EqualTo(u64),
+}
+
+impl Condition {
+    pub fn test<A: Arithmetic>(
+        &self,
+        arithmetic: &A,
+        worry: &A::Worry,
+    ) -> Result<bool, WorryError> {
+        match *self {
+            Condition::DivisibleBy(n) => arithmetic.divisible_by(worry, n),
+            Condition::GreaterThan(n) => Ok(arithmetic.compare(worry, n)? == Ordering::Greater),
+            Condition::LessThan(n) => Ok(arithmetic.compare(worry, n)? == Ordering::Less),
+            Condition::EqualTo(n) => Ok(arithmetic.compare(worry, n)? == Ordering::Equal),
+        }
+    }
+
+    /// The divisor of a divisibility test
+    pub fn divisor(&self) -> Option<u64> {
+        match *self {
+            Condition::DivisibleBy(n) => Some(n),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Condition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Condition::DivisibleBy(n) => write!(f, "divisible by {n}"),
+            Condition::GreaterThan(n) => write!(f, "greater than {n}"),
+            Condition::LessThan(n) => write!(f, "less than {n}"),
+            Condition::EqualTo(n) => write!(f, "equal to {n}"),
+        }
+    }
+}
+
+fn atom(input: &str) -> IResult<&str, Expr> {
+    delimited(
+        space0,
+        alt((
+            tag("old").map(|_| Expr::Old),
+            u64.map(Expr::Num),
+            delimited(char('('), expr, char(')')),
+        )),
+        space0,
+    )(input)
+}
+
+/// `operand`s separated by any of the `operators`, grouped from the left
+fn binary<'a>(
+    mut operand: impl FnMut(&'a str) -> IResult<&'a str, Expr>,
+    operators: &'static str,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Expr> {
+    move |input| {
+        let (input, first) = operand(input)?;
+        fold_many0(
+            pair(one_of(operators), &mut operand),
+            move || first.clone(),
+            |lhs, (op, rhs)| Expr::binary(lhs, BinOp::from_symbol(op), rhs),
+        )(input)
+    }
+}
+
+fn term(input: &str) -> IResult<&str, Expr> {
+    binary(atom, "*/%")(input)
+}
+
+pub fn expr(input: &str) -> IResult<&str, Expr> {
+    binary(term, "+-")(input)
+}
+
+pub fn condition(input: &str) -> IResult<&str, Condition> {
+    alt((
+        // Nothing is divisible by 0
+        preceded(tag("divisible by "), verify(u64, |&n| n != 0)).map(Condition::DivisibleBy),
+        preceded(tag("greater than "), u64).map(Condition::GreaterThan),
+        preceded(tag("less than "), u64).map(Condition::LessThan),
+        preceded(tag("equal to "), u64).map(Condition::EqualTo),
+    ))(input)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        expr::{condition, expr, BinOp, Condition, Expr},
+        worry::{Checked, Modular, WorryError},
+    };
+    use color_eyre::Result;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    fn eval(input: &str, old: u64) -> Result<u64, WorryError> {
+        let (rest, expr) = expr(input).unwrap();
+        assert_eq!(rest, "", "{input}");
+        expr.eval(&Checked, &old)
+    }
+
+    #[test]
+    fn puzzle_operations() -> Result<()> {
+        assert_eq!(
+            expr("old * 19")?.1,
+            Expr::binary(Expr::Old, BinOp::Mul, Expr::Num(19))
+        );
+        assert_eq!(eval("old + 6", 3), Ok(9));
+        assert_eq!(eval("old * old", 3), Ok(9));
+
+        Ok(())
+    }
+
+    #[test]
+    fn precedence() {
+        assert_eq!(eval("old + 2 * old", 3), Ok(9));
+        assert_eq!(eval("(old + 2) * old", 3), Ok(15));
+        assert_eq!(eval("old - 1 - 1", 3), Ok(1));
+        assert_eq!(eval("old - (1 - 1)", 3), Ok(3));
+        assert_eq!(eval("old % 7 / 2 + ((old))", 10), Ok(11));
+        assert_eq!(eval("old - 4", 3), Err(WorryError::Underflow));
+        assert_eq!(eval("old / (old - 3)", 3), Err(WorryError::DivisionByZero));
+    }
+
+    #[test]
+    fn display() -> Result<()> {
+        for input in [
+            "old * 19",
+            "old + 2 * old",
+            "(old + 2) * old",
+            "old - 1 - 1",
+            "old - (1 - 1)",
+            "old / (old * 2) % 5",
+        ] {
+            let (_, parsed) = expr(input)?;
+            assert_eq!(parsed.to_string(), input);
+        }
+        assert_eq!(expr("((old))+1")?.1.to_string(), "old + 1");
+
+        Ok(())
+    }
+
+    #[test]
+    fn conditions() -> Result<()> {
+        let (rest, test) = condition("greater than 5")?;
+        assert_eq!(rest, "");
+        assert_eq!(test, Condition::GreaterThan(5));
+        assert_eq!(test.to_string(), "greater than 5");
+        assert!(condition("divisible by 0").is_err());
+
+        assert_eq!(test.test(&Checked, &6), Ok(true));
+        assert_eq!(Condition::EqualTo(6).test(&Checked, &6), Ok(true));
+        assert_eq!(Condition::DivisibleBy(4).test(&Checked, &6), Ok(false));
+
+        let modular = Modular::new([4])?;
+        assert_eq!(Condition::DivisibleBy(4).test(&modular, &0), Ok(true));
+        assert_eq!(
+            test.test(&modular, &1),
+            Err(WorryError::NotModular("compare", 4))
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,391 @@
+use aoc_runner::Solution;
+use color_eyre::{Report, Result};
+use itertools::Itertools;
+pub use parse::Monkey;
+use worry::{Arithmetic, Big, Checked, Mode, Modular, WorryError};
+
+pub mod expr;
+pub mod worry;
+
+#[allow(dead_code)]
+fn empty_option_err() -> Report {
+    use std::io::{Error, ErrorKind::Other};
+    Error::new(Other, "Option was empty").into()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 11;
+
+    type Parsed = Vec<Monkey>;
+    type Prepared = Vec<Monkey>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        Ok(aoc_runner::parse::complete(parse::input, input)?)
+    }
+
+    fn prepare(monkeys: Self::Parsed) -> Result<Self::Prepared> {
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Self::Prepared) -> Result<Self::Answer1> {
+        Ok(monkey_bussiness(&play(monkeys, &Config::PART1)?))
+    }
+
+    fn part2(monkeys: &Self::Prepared) -> Result<Self::Answer2> {
+        Ok(monkey_bussiness(&play(monkeys, &Config::PART2)?))
+    }
+}
+
+/// Product of the two largest inspection counts
+pub fn monkey_bussiness(inspect_count: &[usize]) -> i64 {
+    let mut biggest = inspect_count.iter().map(|&n| -(n as i64)).k_smallest(2);
+    let (Some(c1), Some(c2), None) = (biggest.next(), biggest.next(), biggest.next()) else {
+        panic!("Didn't have only 2 elements")
+    };
+    c1 * c2
+}
+
+/// How to play the rounds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub rounds: usize,
+    /// The worry levels are divided by this after each inspection
+    pub relief: u64,
+    pub mode: Mode,
+}
+
+impl Config {
+    pub const PART1: Config = Config {
+        rounds: 20,
+        relief: 3,
+        mode: Mode::Checked,
+    };
+    pub const PART2: Config = Config {
+        rounds: 10_000,
+        relief: 1,
+        mode: Mode::Modular,
+    };
+}
+
+/// Number of items inspected by each monkey
+pub fn play(monkeys: &[Monkey], config: &Config) -> Result<Vec<usize>, WorryError> {
+    match config.mode {
+        Mode::Checked => play_with(&Checked, monkeys, config),
+        Mode::Modular => {
+            let modular = Modular::new(
+                monkeys
+                    .iter()
+                    .filter_map(|monkey| monkey.test.condition.divisor()),
+            )?;
+            play_with(&modular, monkeys, config)
+        }
+        Mode::Big => play_with(&Big, monkeys, config),
+    }
+}
+
+fn play_with<A: Arithmetic>(
+    arithmetic: &A,
+    monkeys: &[Monkey],
+    config: &Config,
+) -> Result<Vec<usize>, WorryError> {
+    let mut items: Vec<Vec<A::Worry>> = monkeys
+        .iter()
+        .map(|monkey| {
+            monkey
+                .items
+                .iter()
+                .map(|&level| arithmetic.worry(level))
+                .collect()
+        })
+        .collect();
+    let mut inspected = vec![0; monkeys.len()];
+
+    for _ in 0..config.rounds {
+        for (ix, monkey) in monkeys.iter().enumerate() {
+            let held = std::mem::take(&mut items[ix]);
+            inspected[ix] += held.len();
+
+            for worry in held {
+                let worry = monkey.operation.eval(arithmetic, &worry)?;
+                let worry = arithmetic.relieve(&worry, config.relief)?;
+                let passed = monkey.test.condition.test(arithmetic, &worry)?;
+                items[monkey.test.target(passed)].push(worry);
+            }
+        }
+    }
+
+    Ok(inspected)
+}
+
+mod parse {
+    use aoc_runner::parse::IResult;
+    use nom::{
+        character::complete::{self, line_ending, multispace0, space1},
+        combinator::{eof, map},
+        error::{ErrorKind, FromExternalError},
+        multi::{many1, separated_list1},
+        sequence::{delimited, pair, preceded, terminated, tuple},
+    };
+    use nom_supreme::{error::ErrorTree, tag::complete::tag};
+
+    use crate::expr::{condition, expr, Condition, Expr};
+
+    pub type ItemWorry = u64;
+
+    #[derive(Debug, Clone)]
+    pub struct Monkey {
+        /// Worry levels of the items it starts with
+        pub items: Vec<ItemWorry>,
+        pub operation: Expr,
+        pub test: Test,
+    }
+
+    impl From<(Vec<ItemWorry>, Expr, Test)> for Monkey {
+        fn from((items, operation, test): (Vec<ItemWorry>, Expr, Test)) -> Self {
+            Self {
+                items,
+                operation,
+                test,
+            }
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Test {
+        pub condition: Condition,
+        throw_passed_test: u32,
+        throw_failed_test: u32,
+    }
+
+    impl Test {
+        /// The monkey the item is thrown to
+        pub fn target(&self, passed: bool) -> usize {
+            if passed {
+                self.throw_passed_test as usize
+            } else {
+                self.throw_failed_test as usize
+            }
+        }
+
+        /// The test and where its targets are in the input, to check that they exist
+        fn parse(input: &str) -> IResult<&str, (Self, [Target<'_>; 2])> {
+            map(
+                tuple((
+                    delimited(pair(space1, tag("Test: ")), condition, line_ending),
+                    delimited(
+                        pair(space1, tag("If true: throw to monkey ")),
+                        target,
+                        line_ending,
+                    ),
+                    delimited(
+                        pair(space1, tag("If false: throw to monkey ")),
+                        target,
+                        line_ending,
+                    ),
+                )),
+                |(condition, passed, failed)| {
+                    let test = Test {
+                        condition,
+                        throw_passed_test: passed.1,
+                        throw_failed_test: failed.1,
+                    };
+                    (test, [passed, failed])
+                },
+            )(input)
+        }
+    }
+
+    /// A monkey to throw to and where it is in the input
+    type Target<'a> = (&'a str, u32);
+
+    fn target(input: &str) -> IResult<&str, Target<'_>> {
+        let (rest, monkey) = complete::u32(input)?;
+        Ok((rest, (input, monkey)))
+    }
+
+    #[derive(Debug, thiserror::Error)]
+    enum BadMonkeys {
+        #[error("one of the {0} monkeys")]
+        NoSuchMonkey(usize),
+        #[error("at least two monkeys")]
+        TooFew,
+    }
+
+    fn bad_monkeys(location: &str, err: BadMonkeys) -> nom::Err<ErrorTree<&str>> {
+        nom::Err::Failure(ErrorTree::from_external_error(
+            location,
+            ErrorKind::Verify,
+            err,
+        ))
+    }
+
+    pub fn input(input: &str) -> IResult<&str, Vec<Monkey>> {
+        let (rest, monkeys) = terminated(
+            many1(terminated(
+                preceded(
+                    delimited(tag("Monkey "), complete::u32, pair(tag(":"), line_ending)),
+                    tuple((
+                        delimited(
+                            pair(space1, tag("Starting items: ")),
+                            separated_list1(tag(", "), complete::u64),
+                            line_ending,
+                        ),
+                        delimited(pair(space1, tag("Operation: new = ")), expr, line_ending),
+                        Test::parse,
+                    )),
+                ),
+                multispace0,
+            )),
+            eof,
+        )(input)?;
+
+        // The monkeys can throw to the ones further down, so check once they are all parsed
+        let count = monkeys.len();
+        if count < 2 {
+            return Err(bad_monkeys(rest, BadMonkeys::TooFew));
+        }
+        let targets = monkeys.iter().flat_map(|(_, _, (_, targets))| targets);
+        if let Some(&(location, _)) = targets
+            .into_iter()
+            .find(|(_, monkey)| *monkey as usize >= count)
+        {
+            return Err(bad_monkeys(location, BadMonkeys::NoSuchMonkey(count)));
+        }
+
+        let monkeys = monkeys
+            .into_iter()
+            .map(|(items, operation, (test, _))| Monkey::from((items, operation, test)))
+            .collect();
+        Ok((rest, monkeys))
+    }
+}
+
+aoc_runner::examples! {
+    Day11,
+    "test_input" => { part1: 10605, part2: 2713310158u64 },
+}
+
+#[cfg(test)]
+mod test {
+    use color_eyre::Result;
+    use pretty_assertions::assert_eq;
+
+    use aoc_runner::{parse::BadInput, Solution};
+
+    use crate::{
+        parse::input,
+        play,
+        worry::{Mode, WorryError},
+        Config, Day11,
+    };
+
+    static INPUT: &str = include_str!("test_input");
+
+    static CUSTOM_INPUT: &str = "\
+Monkey 0:
+  Starting items: 10, 3
+  Operation: new = (old + 1) * 2 - old
+  Test: greater than 10
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items: 4
+  Operation: new = old % 5 + old / 2
+  Test: less than 4
+    If true: throw to monkey 0
+    If false: throw to monkey 0
+";
+
+    #[test]
+    fn worry_modes() -> Result<()> {
+        let (_, monkeys) = input(INPUT)?;
+        let config = |mode| Config {
+            rounds: 20,
+            relief: 1,
+            mode,
+        };
+
+        assert_eq!(play(&monkeys, &config(Mode::Modular))?, [99, 97, 8, 103]);
+        assert_eq!(play(&monkeys, &config(Mode::Big))?, [99, 97, 8, 103]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn worry_errors() -> Result<()> {
+        let (_, monkeys) = input(INPUT)?;
+
+        let checked = Config {
+            mode: Mode::Checked,
+            ..Config::PART2
+        };
+        assert_eq!(play(&monkeys, &checked), Err(WorryError::Overflow));
+
+        let modular = Config {
+            mode: Mode::Modular,
+            ..Config::PART1
+        };
+        assert_eq!(
+            play(&monkeys, &modular),
+            Err(WorryError::ReliefWithModulus(3, 96577))
+        );
+
+        // Relief keeps the worry levels small enough
+        let big = Config {
+            mode: Mode::Big,
+            ..Config::PART1
+        };
+        assert_eq!(play(&monkeys, &big)?, play(&monkeys, &Config::PART1)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn custom_monkeys() -> Result<()> {
+        let (rest, monkeys) = input(CUSTOM_INPUT)?;
+        assert_eq!(rest, "");
+        assert_eq!(monkeys[0].operation.to_string(), "(old + 1) * 2 - old");
+        assert_eq!(monkeys[1].test.condition.to_string(), "less than 4");
+
+        let config = |mode| Config {
+            rounds: 2,
+            relief: 1,
+            mode,
+        };
+        assert_eq!(play(&monkeys, &config(Mode::Checked))?, [5, 6]);
+        assert_eq!(play(&monkeys, &config(Mode::Big))?, [5, 6]);
+        assert_eq!(
+            play(&monkeys, &config(Mode::Modular)),
+            Err(WorryError::NotModular("compare", 1))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_monkeys() {
+        let bad_target =
+            CUSTOM_INPUT.replace("If false: throw to monkey 0", "If false: throw to monkey 2");
+        let err = Day11::parse(Box::leak(bad_target.into_boxed_str())).unwrap_err();
+        let diagnostic = err.downcast_ref::<BadInput>().unwrap().diagnostic();
+        assert_eq!((diagnostic.line, diagnostic.column), (13, 31));
+        assert_eq!(diagnostic.expected, ["one of the 2 monkeys"]);
+
+        let single = &CUSTOM_INPUT[..CUSTOM_INPUT.find("\n\n").unwrap() + 1];
+        let err = Day11::parse(single).unwrap_err();
+        let diagnostic = err.downcast_ref::<BadInput>().unwrap().diagnostic();
+        assert_eq!(diagnostic.expected, ["at least two monkeys"]);
+    }
+
+    #[test]
+    fn zero_divisor() {
+        let zero = CUSTOM_INPUT.replace("greater than 10", "divisible by 0");
+        assert!(input(&zero).is_err());
+    }
+}
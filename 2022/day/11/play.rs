@@ -0,0 +1,65 @@
+//! Play the monkeys' rounds with any number of rounds, relief and worry arithmetic
+//!
+//! Usage: `aoc-2022-11-play [OPTIONS] [INPUT]`, the input is found like for the solution.
+//! Options not given are taken from the configuration of `--part`.
+use std::path::PathBuf;
+
+use aoc_2022_11::{monkey_bussiness, play, worry::Mode, Config, Day11};
+use aoc_runner::{input, Solution};
+use clap::Parser;
+use color_eyre::Result;
+
+/// Count the items each monkey of day 11 inspects
+#[derive(Debug, Parser)]
+struct Args {
+    /// Start from the configuration of this part
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Number of rounds to play
+    #[arg(short, long)]
+    rounds: Option<usize>,
+    /// Divide the worry levels by this after each inspection
+    #[arg(long)]
+    relief: Option<u64>,
+    /// Keep track of the worry levels with checked, modular or big integers
+    #[arg(short, long)]
+    mode: Option<Mode>,
+    /// Read the input from this file (`-` for stdin) instead of the default location
+    input: Option<PathBuf>,
+}
+
+impl Args {
+    fn config(&self) -> Config {
+        let base = match self.part {
+            1 => Config::PART1,
+            _ => Config::PART2,
+        };
+        Config {
+            rounds: self.rounds.unwrap_or(base.rounds),
+            relief: self.relief.unwrap_or(base.relief),
+            mode: self.mode.unwrap_or(base.mode),
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+
+    let args = Args::parse();
+    let config = args.config();
+
+    let source = args
+        .input
+        .as_ref()
+        .map(|path| input::Source::from(path.as_os_str()));
+    let input = input::load(Day11::YEAR, Day11::DAY, source.as_ref())?;
+    let monkeys = Day11::parse(input)?;
+
+    let inspected = play(&monkeys, &config)?;
+    for (ix, count) in inspected.iter().enumerate() {
+        println!("Monkey {ix} inspected items {count} times.");
+    }
+    println!("Monkey business: {}", monkey_bussiness(&inspected));
+
+    Ok(())
+}
@@ -0,0 +1,316 @@
+//! The arithmetic of worry levels, which grow too fast for plain integers
+//!
+//! Each [`Arithmetic`] keeps track of worry levels differently:
+//!
+//! - [`Checked`]: plain `u64`, failing instead of overflowing
+//! - [`Modular`]: `u64` modulo the LCM of the divisors the monkeys test, which doesn't
+//!   change the outcome of any test but can't be divided to relieve the worry
+//! - [`Big`]: arbitrary precision, exact but slow, to check the others
+use std::{cmp::Ordering, fmt::Debug, str::FromStr};
+
+use num::{integer::lcm, BigUint, Zero};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum WorryError {
+    #[error("the worry level overflowed a u64")]
+    Overflow,
+    #[error("the worry level went below zero")]
+    Underflow,
+    #[error("divided the worry level by zero")]
+    DivisionByZero,
+    #[error("can't divide the worry level by {0} when it's modulo {1}")]
+    ReliefWithModulus(u64, u64),
+    #[error("can't {0} worry levels modulo {1}")]
+    NotModular(&'static str, u64),
+    #[error("can't test if worry levels modulo {1} are divisible by {0}, which doesn't divide it")]
+    NotADivisor(u64, u64),
+}
+
+pub trait Arithmetic {
+    type Worry: Clone + Debug;
+
+    fn worry(&self, level: u64) -> Self::Worry;
+
+    fn add(&self, a: &Self::Worry, b: &Self::Worry) -> Result<Self::Worry, WorryError>;
+
+    fn sub(&self, a: &Self::Worry, b: &Self::Worry) -> Result<Self::Worry, WorryError>;
+
+    fn mul(&self, a: &Self::Worry, b: &Self::Worry) -> Result<Self::Worry, WorryError>;
+
+    fn div(&self, a: &Self::Worry, b: &Self::Worry) -> Result<Self::Worry, WorryError>;
+
+    fn rem(&self, a: &Self::Worry, b: &Self::Worry) -> Result<Self::Worry, WorryError>;
+
+    /// Divide the worry level by `relief`, rounding down
+    fn relieve(&self, worry: &Self::Worry, relief: u64) -> Result<Self::Worry, WorryError>;
+
+    /// Fails with [`WorryError::DivisionByZero`] for a `divisor` of 0, in every mode
+    fn divisible_by(&self, worry: &Self::Worry, divisor: u64) -> Result<bool, WorryError>;
+
+    fn compare(&self, worry: &Self::Worry, level: u64) -> Result<Ordering, WorryError>;
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Checked;
+
+impl Arithmetic for Checked {
+    type Worry = u64;
+
+    fn worry(&self, level: u64) -> u64 {
+        level
+    }
+
+    fn add(&self, a: &u64, b: &u64) -> Result<u64, WorryError> {
+        a.checked_add(*b).ok_or(WorryError::Overflow)
+    }
+
+    fn sub(&self, a: &u64, b: &u64) -> Result<u64, WorryError> {
+        a.checked_sub(*b).ok_or(WorryError::Underflow)
+    }
+
+    fn mul(&self, a: &u64, b: &u64) -> Result<u64, WorryError> {
+        a.checked_mul(*b).ok_or(WorryError::Overflow)
+    }
+
+    fn div(&self, a: &u64, b: &u64) -> Result<u64, WorryError> {
+        a.checked_div(*b).ok_or(WorryError::DivisionByZero)
+    }
+
+    fn rem(&self, a: &u64, b: &u64) -> Result<u64, WorryError> {
+        a.checked_rem(*b).ok_or(WorryError::DivisionByZero)
+    }
+
+    fn relieve(&self, worry: &u64, relief: u64) -> Result<u64, WorryError> {
+        self.div(worry, &relief)
+    }
+
+    fn divisible_by(&self, worry: &u64, divisor: u64) -> Result<bool, WorryError> {
+        Ok(self.rem(worry, &divisor)? == 0)
+    }
+
+    fn compare(&self, worry: &u64, level: u64) -> Result<Ordering, WorryError> {
+        Ok(worry.cmp(&level))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Modular {
+    modulus: u64,
+}
+
+impl Modular {
+    /// Worry levels modulo the LCM of the `divisors`, none of which can be 0
+    pub fn new(divisors: impl IntoIterator<Item = u64>) -> Result<Self, WorryError> {
+        let modulus = divisors
+            .into_iter()
+            .try_fold(1, |modulus, divisor| match divisor {
+                0 => Err(WorryError::DivisionByZero),
+                _ => Ok(lcm(modulus, divisor)),
+            })?;
+        Ok(Self { modulus })
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+}
+
+impl Arithmetic for Modular {
+    type Worry = u64;
+
+    fn worry(&self, level: u64) -> u64 {
+        level % self.modulus
+    }
+
+    fn add(&self, a: &u64, b: &u64) -> Result<u64, WorryError> {
+        Ok(((*a as u128 + *b as u128) % self.modulus as u128) as u64)
+    }
+
+    fn sub(&self, a: &u64, b: &u64) -> Result<u64, WorryError> {
+        let modulus = self.modulus as u128;
+        Ok(((*a as u128 % modulus + modulus - *b as u128 % modulus) % modulus) as u64)
+    }
+
+    fn mul(&self, a: &u64, b: &u64) -> Result<u64, WorryError> {
+        Ok(((*a as u128 * *b as u128) % self.modulus as u128) as u64)
+    }
+
+    fn div(&self, _a: &u64, _b: &u64) -> Result<u64, WorryError> {
+        Err(WorryError::NotModular("divide", self.modulus))
+    }
+
+    fn rem(&self, _a: &u64, _b: &u64) -> Result<u64, WorryError> {
+        Err(WorryError::NotModular(
+            "take the remainder of",
+            self.modulus,
+        ))
+    }
+
+    fn relieve(&self, worry: &u64, relief: u64) -> Result<u64, WorryError> {
+        match relief {
+            1 => Ok(*worry),
+            _ => Err(WorryError::ReliefWithModulus(relief, self.modulus)),
+        }
+    }
+
+    fn divisible_by(&self, worry: &u64, divisor: u64) -> Result<bool, WorryError> {
+        // Only divisors of the modulus keep their remainder
+        match divisor {
+            0 => Err(WorryError::DivisionByZero),
+            _ if !self.modulus.is_multiple_of(divisor) => {
+                Err(WorryError::NotADivisor(divisor, self.modulus))
+            }
+            _ => Ok(worry.is_multiple_of(divisor)),
+        }
+    }
+
+    fn compare(&self, _worry: &u64, _level: u64) -> Result<Ordering, WorryError> {
+        Err(WorryError::NotModular("compare", self.modulus))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Big;
+
+impl Arithmetic for Big {
+    type Worry = BigUint;
+
+    fn worry(&self, level: u64) -> BigUint {
+        level.into()
+    }
+
+    fn add(&self, a: &BigUint, b: &BigUint) -> Result<BigUint, WorryError> {
+        Ok(a + b)
+    }
+
+    fn sub(&self, a: &BigUint, b: &BigUint) -> Result<BigUint, WorryError> {
+        num::CheckedSub::checked_sub(a, b).ok_or(WorryError::Underflow)
+    }
+
+    fn mul(&self, a: &BigUint, b: &BigUint) -> Result<BigUint, WorryError> {
+        Ok(a * b)
+    }
+
+    fn div(&self, a: &BigUint, b: &BigUint) -> Result<BigUint, WorryError> {
+        num::CheckedDiv::checked_div(a, b).ok_or(WorryError::DivisionByZero)
+    }
+
+    fn rem(&self, a: &BigUint, b: &BigUint) -> Result<BigUint, WorryError> {
+        if b.is_zero() {
+            return Err(WorryError::DivisionByZero);
+        }
+        Ok(a % b)
+    }
+
+    fn relieve(&self, worry: &BigUint, relief: u64) -> Result<BigUint, WorryError> {
+        self.div(worry, &relief.into())
+    }
+
+    fn divisible_by(&self, worry: &BigUint, divisor: u64) -> Result<bool, WorryError> {
+        Ok(self.rem(worry, &divisor.into())?.is_zero())
+    }
+
+    fn compare(&self, worry: &BigUint, level: u64) -> Result<Ordering, WorryError> {
+        Ok(worry.cmp(&level.into()))
+    }
+}
+
+/// Which [`Arithmetic`] to use, chosen at runtime
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Checked,
+    Modular,
+    Big,
+}
+
+impl FromStr for Mode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "checked" => Ok(Mode::Checked),
+            "modular" => Ok(Mode::Modular),
+            "big" => Ok(Mode::Big),
+            _ => Err(format!(
+                "unknown worry mode `{s}`, expected checked, modular or big"
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::worry::{Arithmetic, Big, Checked, Mode, Modular, WorryError};
+    use num::BigUint;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn checked() {
+        let big = Checked.worry(u64::MAX / 2 + 1);
+        assert_eq!(Checked.add(&big, &big), Err(WorryError::Overflow));
+        assert_eq!(Checked.mul(&big, &2), Err(WorryError::Overflow));
+        assert_eq!(Checked.relieve(&10, 3), Ok(3));
+        assert_eq!(Checked.sub(&2, &3), Err(WorryError::Underflow));
+        assert_eq!(Checked.rem(&2, &0), Err(WorryError::DivisionByZero));
+        assert_eq!(Checked.divisible_by(&6, 3), Ok(true));
+    }
+
+    #[test]
+    fn modular() {
+        let modular = Modular::new([6, 4, 3]).unwrap();
+        assert_eq!(modular.modulus(), 12);
+
+        // u64::MAX is 3 modulo 12, multiplying doesn't overflow
+        let a = modular.worry(u64::MAX);
+        assert_eq!(a, 3);
+        assert_eq!(modular.mul(&u64::MAX, &u64::MAX), Ok(9));
+        assert_eq!(modular.add(&11, &5), Ok(4));
+        assert_eq!(modular.sub(&3, &5), Ok(10));
+        assert_eq!(
+            modular.div(&6, &2),
+            Err(WorryError::NotModular("divide", 12))
+        );
+        assert_eq!(modular.relieve(&7, 1), Ok(7));
+        assert_eq!(modular.divisible_by(&8, 4), Ok(true));
+        // 24 is 0 modulo 12 but not divisible by 5
+        assert_eq!(
+            modular.divisible_by(&modular.worry(24), 5),
+            Err(WorryError::NotADivisor(5, 12))
+        );
+        assert_eq!(
+            modular.relieve(&7, 3),
+            Err(WorryError::ReliefWithModulus(3, 12))
+        );
+    }
+
+    #[test]
+    fn big() {
+        let a = Big.worry(u64::MAX);
+        let square = Big.mul(&a, &a).unwrap();
+        assert_eq!(square, BigUint::from(u64::MAX as u128 * u64::MAX as u128));
+        assert_eq!(Big.divisible_by(&square, 5), Ok(true));
+        assert_eq!(Big.relieve(&square, u64::MAX), Ok(a));
+    }
+
+    #[test]
+    fn zero_divisor() {
+        assert_eq!(
+            Modular::new([3, 0, 5]).unwrap_err(),
+            WorryError::DivisionByZero
+        );
+        let modular = Modular::new([3]).unwrap();
+        assert_eq!(Checked.divisible_by(&6, 0), Err(WorryError::DivisionByZero));
+        assert_eq!(modular.divisible_by(&0, 0), Err(WorryError::DivisionByZero));
+        assert_eq!(
+            Big.divisible_by(&6u32.into(), 0),
+            Err(WorryError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!("modular".parse(), Ok(Mode::Modular));
+        assert!("float".parse::<Mode>().is_err());
+    }
+}
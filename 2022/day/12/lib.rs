@@ -0,0 +1,113 @@
+use aoc_runner::Solution;
+use aoc_utils::grid::Grid;
+use color_eyre::{eyre::eyre, Report, Result};
+pub use parse::HeightMapPoint;
+use terrain::{Climb, Terrain};
+
+pub mod render;
+pub mod terrain;
+
+#[allow(dead_code)]
+fn empty_option_err() -> Report {
+    use std::io::{Error, ErrorKind::Other};
+    Error::new(Other, "Option was empty").into()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 12;
+
+    type Parsed = Grid<HeightMapPoint>;
+    type Prepared = Terrain;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        Ok(aoc_runner::parse::complete(parse::input, input)?)
+    }
+
+    fn prepare(height_map: Self::Parsed) -> Result<Self::Prepared> {
+        Ok(Terrain::new(height_map))
+    }
+
+    fn part1(terrain: &Self::Prepared) -> Result<Self::Answer1> {
+        terrain
+            .path_from_start(&Climb)
+            .map(|path| path.cost)
+            .ok_or_else(|| eyre!("End unreachable"))
+    }
+
+    fn part2(terrain: &Self::Prepared) -> Result<Self::Answer2> {
+        terrain
+            .path_from_lowest(&Climb)
+            .map(|path| path.cost)
+            .ok_or_else(|| eyre!("End unreachable"))
+    }
+}
+
+mod parse {
+    use aoc_runner::parse::IResult;
+    use aoc_utils::grid::{rows, Grid};
+    use nom::{character::complete::one_of, combinator::map_res};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum HeightMapPoint {
+        Start,
+        Point(u8),
+        End,
+    }
+
+    impl HeightMapPoint {
+        pub fn height(self) -> u8 {
+            match self {
+                HeightMapPoint::Start => 0,
+                HeightMapPoint::Point(h) => h,
+                HeightMapPoint::End => b'z' - b'a',
+            }
+        }
+
+        pub fn can_move_to(self, to: HeightMapPoint) -> bool {
+            to.height() <= self.height() + 1
+        }
+
+        /// The character representing this point in the input
+        pub fn to_char(self) -> char {
+            match self {
+                HeightMapPoint::Start => 'S',
+                HeightMapPoint::Point(h) => (b'a' + h) as char,
+                HeightMapPoint::End => 'E',
+            }
+        }
+    }
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("invalid height {0:?}")]
+    pub struct InvalidHeight(char);
+
+    impl TryFrom<char> for HeightMapPoint {
+        type Error = InvalidHeight;
+
+        fn try_from(value: char) -> Result<Self, Self::Error> {
+            match value {
+                'a'..='z' => Ok(HeightMapPoint::Point(value as u8 - b'a')),
+                'S' => Ok(HeightMapPoint::Start),
+                'E' => Ok(HeightMapPoint::End),
+                _ => Err(InvalidHeight(value)),
+            }
+        }
+    }
+
+    pub fn input(input: &str) -> IResult<&str, Grid<HeightMapPoint>> {
+        rows(map_res(
+            one_of("abcdefghijklmnopqrstuvwxyzSE"),
+            HeightMapPoint::try_from,
+        ))(input)
+    }
+}
+
+aoc_runner::examples! {
+    Day12,
+    "test_input" => { part1: 31, part2: 29 },
+}
@@ -0,0 +1,114 @@
+//! Pictures of the paths found on the height map, to see why a route is or isn't found
+use aoc_utils::{
+    grid::{Grid, Pos},
+    image::{gray, heat, Image, WHITE},
+    vector::Vec2,
+};
+
+use crate::{terrain::Terrain, HeightMapPoint};
+
+/// The path drawn with arrows pointing to the next step, like in the puzzle text
+pub fn arrows(terrain: &Terrain, path: &[Pos]) -> Grid<char> {
+    let mut rendered = terrain.height_map.map(|_, _| '.');
+    for (&from, &to) in path.iter().zip(path.iter().skip(1)) {
+        rendered[from] = arrow(to - from);
+    }
+    if let Some(&goal) = path.last() {
+        rendered[goal] = terrain.height_map[goal].to_char();
+    }
+    rendered
+}
+
+fn arrow(step: Pos) -> char {
+    match (step.x, step.y) {
+        (1, 0) => '>',
+        (-1, 0) => '<',
+        (0, -1) => '^',
+        (0, 1) => 'v',
+        _ => '?',
+    }
+}
+
+/// Each point colored by its distance to the end, from blue (close) to red (far)
+///
+/// Points that can't reach the end are gray, darker the lower they are, and the points
+/// along `path` are white.
+pub fn heat_map(terrain: &Terrain, distances: &Grid<Option<usize>>, path: &[Pos]) -> Image {
+    let farthest = distances
+        .cells()
+        .iter()
+        .flatten()
+        .max()
+        .copied()
+        .unwrap_or(0);
+    let highest = f64::from(HeightMapPoint::End.height());
+
+    let mut image = Image::from_grid(distances, |pos, distance| match distance {
+        Some(distance) => heat(*distance as f64 / farthest.max(1) as f64),
+        None => gray(0.2 + 0.5 * f64::from(terrain.height_map[pos].height()) / highest),
+    });
+    let min = distances.min();
+    for &pos in path {
+        let Vec2 { x, y } = pos - min;
+        if let Some(pixel) = image.pixel_mut(x as usize, y as usize) {
+            *pixel = WHITE;
+        }
+    }
+    image
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        parse,
+        render::{arrow, arrows, heat_map},
+        terrain::{Climb, Terrain},
+    };
+    use aoc_utils::image::WHITE;
+    use color_eyre::Result;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    static INPUT: &str = include_str!("test_input");
+
+    #[test]
+    fn follow_the_arrows() -> Result<()> {
+        let terrain = Terrain::new(parse::input(INPUT)?.1);
+        let path = terrain.path_from_start(&Climb).unwrap();
+        let rendered = arrows(&terrain, &path.nodes);
+
+        let mut pos = *path.start();
+        let mut steps = 0;
+        while rendered[pos] != 'E' {
+            pos = pos
+                + terrain
+                    .height_map
+                    .neighbors4(pos)
+                    .map(|next| next - pos)
+                    .find(|&step| arrow(step) == rendered[pos])
+                    .unwrap();
+            steps += 1;
+        }
+        assert_eq!(steps, 31);
+        assert_eq!(rendered.cells().iter().filter(|&&c| c != '.').count(), 32);
+
+        Ok(())
+    }
+
+    #[test]
+    fn heat_map_marks_the_path() -> Result<()> {
+        let terrain = Terrain::new(parse::input(INPUT)?.1);
+        let path = terrain.path_from_start(&Climb).unwrap();
+        let image = heat_map(&terrain, &terrain.distances(&Climb), &path.nodes);
+
+        assert_eq!((image.width(), image.height()), (8, 5));
+        let white = image
+            .pixels()
+            .iter()
+            .filter(|&&pixel| pixel == WHITE)
+            .count();
+        assert_eq!(white, path.nodes.len());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,277 @@
+//! Path finding over the height map with pluggable move rules
+//!
+//! A [`MoveRule`] decides which moves between neighboring points are allowed and what
+//! they cost, [`Terrain::path`] finds the cheapest path to the end following that rule.
+use std::{fmt::Display, ops::Add};
+
+use aoc_utils::{
+    grid::{Grid, Pos},
+    search::{self, Path},
+};
+
+use crate::parse::HeightMapPoint;
+
+/// Which moves between neighboring points are allowed and what they cost
+pub trait MoveRule {
+    type Cost: Copy + Ord + Default + Add<Output = Self::Cost>;
+
+    fn allowed(&self, from: HeightMapPoint, to: HeightMapPoint) -> bool;
+
+    fn cost(&self, from: HeightMapPoint, to: HeightMapPoint) -> Self::Cost;
+}
+
+/// The rule of the puzzle: climb at most one step, every move costs the same
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Climb;
+
+impl MoveRule for Climb {
+    type Cost = usize;
+
+    fn allowed(&self, from: HeightMapPoint, to: HeightMapPoint) -> bool {
+        from.can_move_to(to)
+    }
+
+    fn cost(&self, _from: HeightMapPoint, _to: HeightMapPoint) -> usize {
+        1
+    }
+}
+
+/// Climb at most one step, but only climbing costs anything
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MinimalClimb;
+
+impl MoveRule for MinimalClimb {
+    type Cost = usize;
+
+    fn allowed(&self, from: HeightMapPoint, to: HeightMapPoint) -> bool {
+        from.can_move_to(to)
+    }
+
+    fn cost(&self, from: HeightMapPoint, to: HeightMapPoint) -> usize {
+        to.height().saturating_sub(from.height()).into()
+    }
+}
+
+/// A rule built from an `allowed` and a `cost` function
+#[derive(Debug, Clone, Copy)]
+pub struct FnRule<A, C> {
+    pub allowed: A,
+    pub cost: C,
+}
+
+impl<A, C> FnRule<A, C> {
+    pub fn new<Cost>(allowed: A, cost: C) -> Self
+    where
+        A: Fn(HeightMapPoint, HeightMapPoint) -> bool,
+        C: Fn(HeightMapPoint, HeightMapPoint) -> Cost,
+    {
+        Self { allowed, cost }
+    }
+}
+
+impl<A, C, Cost> MoveRule for FnRule<A, C>
+where
+    A: Fn(HeightMapPoint, HeightMapPoint) -> bool,
+    C: Fn(HeightMapPoint, HeightMapPoint) -> Cost,
+    Cost: Copy + Ord + Default + Add<Output = Cost>,
+{
+    type Cost = Cost;
+
+    fn allowed(&self, from: HeightMapPoint, to: HeightMapPoint) -> bool {
+        (self.allowed)(from, to)
+    }
+
+    fn cost(&self, from: HeightMapPoint, to: HeightMapPoint) -> Cost {
+        (self.cost)(from, to)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Terrain {
+    pub height_map: Grid<HeightMapPoint>,
+}
+
+impl Terrain {
+    pub fn new(height_map: Grid<HeightMapPoint>) -> Self {
+        Self { height_map }
+    }
+
+    /// Where the paths end
+    pub fn end(&self) -> Option<Pos> {
+        self.height_map
+            .position(|point| matches!(point, HeightMapPoint::End))
+    }
+
+    /// The cheapest path from any point matching `start` to the end following `rule`
+    pub fn path<R: MoveRule>(
+        &self,
+        mut start: impl FnMut(Pos, HeightMapPoint) -> bool,
+        rule: &R,
+    ) -> Option<Path<Pos, R::Cost>> {
+        let end = self.end()?;
+
+        // Search backwards from the end, so the search has a single start
+        let mut path = search::dijkstra(
+            end,
+            |&to| self.moves_to(to, rule),
+            |&pos| start(pos, self.height_map[pos]),
+        )?;
+        path.nodes.reverse();
+
+        Some(path)
+    }
+
+    /// The cheapest path from the start to the end following `rule`
+    pub fn path_from_start<R: MoveRule>(&self, rule: &R) -> Option<Path<Pos, R::Cost>> {
+        self.path(|_, point| matches!(point, HeightMapPoint::Start), rule)
+    }
+
+    /// The cheapest path from any of the lowest points to the end following `rule`
+    pub fn path_from_lowest<R: MoveRule>(&self, rule: &R) -> Option<Path<Pos, R::Cost>> {
+        self.path(|_, point| point.height() == 0, rule)
+    }
+
+    /// The cost of the cheapest path from each point to the end following `rule`, `None`
+    /// for points that can't reach it
+    pub fn distances<R: MoveRule>(&self, rule: &R) -> Grid<Option<R::Cost>> {
+        let Some(end) = self.end() else {
+            return self.height_map.map(|_, _| None);
+        };
+
+        let costs = search::dijkstra_costs(end, |&to| self.moves_to(to, rule));
+        self.height_map.map(|pos, _| costs.get(&pos))
+    }
+
+    /// The moves reaching `to` following `rule`, with their cost
+    fn moves_to<R: MoveRule>(&self, to: Pos, rule: &R) -> Vec<(Pos, R::Cost)> {
+        let to_point = self.height_map[to];
+        self.height_map
+            .neighbors4(to)
+            .map(|from| (from, self.height_map[from]))
+            .filter(|&(_, from_point)| rule.allowed(from_point, to_point))
+            .map(|(from, from_point)| (from, rule.cost(from_point, to_point)))
+            .collect()
+    }
+
+    /// The height map showing only the points along `path`
+    pub fn render_path(&self, path: &[Pos]) -> Grid<char> {
+        let mut rendered = self.height_map.map(|_, _| '.');
+        for &pos in path {
+            rendered[pos] = self.height_map[pos].to_char();
+        }
+        rendered
+    }
+}
+
+impl Display for Terrain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.height_map.map(|_, point| point.to_char()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        parse,
+        terrain::{Climb, FnRule, MinimalClimb, Terrain},
+    };
+    use aoc_utils::grid::Grid;
+    use color_eyre::Result;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    static INPUT: &str = include_str!("test_input");
+
+    fn terrain() -> Result<Terrain> {
+        Ok(Terrain::new(parse::input(INPUT)?.1))
+    }
+
+    #[test]
+    fn shortest_path() -> Result<()> {
+        let terrain = terrain()?;
+
+        let path = terrain.path_from_start(&Climb).unwrap();
+        assert_eq!(path.cost, 31);
+        assert_eq!(
+            terrain.height_map[*path.start()],
+            parse::HeightMapPoint::Start
+        );
+        assert_eq!(Some(*path.goal()), terrain.end());
+
+        let path = terrain.path_from_lowest(&Climb).unwrap();
+        assert_eq!(path.cost, 29);
+
+        Ok(())
+    }
+
+    #[test]
+    fn minimal_climb() -> Result<()> {
+        let terrain = terrain()?;
+
+        let path = terrain.path_from_start(&MinimalClimb).unwrap();
+        assert_eq!(path.cost, 25);
+        for (&from, &to) in path.nodes.iter().zip(&path.nodes[1..]) {
+            assert!(terrain.height_map[from].can_move_to(terrain.height_map[to]));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn custom_rule() -> Result<()> {
+        let terrain = terrain()?;
+
+        // Only climbing or staying at the same height, never going down
+        let upwards = FnRule::new(
+            |from, to| from.height() <= to.height() && from.can_move_to(to),
+            |_, _| 1,
+        );
+        let path = terrain.path_from_start(&upwards).unwrap();
+        // The shortest path of the puzzle never goes down
+        assert_eq!(path.cost, 31);
+        for (&from, &to) in path.nodes.iter().zip(&path.nodes[1..]) {
+            assert!(terrain.height_map[from].height() <= terrain.height_map[to].height());
+        }
+
+        let impossible = FnRule::new(|_, _| false, |_, _| 1);
+        assert_eq!(terrain.path_from_start(&impossible), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn distances() -> Result<()> {
+        let terrain = terrain()?;
+        let distances = terrain.distances(&Climb);
+
+        let end = terrain.end().unwrap();
+        assert_eq!(distances[end], Some(0));
+        for (pos, point) in terrain.height_map.iter() {
+            let path = terrain.path(|from, _| from == pos, &Climb);
+            assert_eq!(
+                distances[pos],
+                path.map(|path| path.cost),
+                "{pos:?} {point:?}"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn render_path() -> Result<()> {
+        let terrain = terrain()?;
+        let path = terrain.path_from_start(&Climb).unwrap();
+
+        let rendered: Grid<char> = terrain.render_path(&path.nodes);
+        assert_eq!(
+            rendered.cells().iter().filter(|&&c| c != '.').count(),
+            path.nodes.len()
+        );
+        assert_eq!(rendered[*path.start()], 'S');
+        assert_eq!(rendered[*path.goal()], 'E');
+        assert_eq!(terrain.to_string(), INPUT);
+
+        Ok(())
+    }
+}
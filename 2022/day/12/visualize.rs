@@ -0,0 +1,58 @@
+//! Print the shortest path over the height map and save the distances as a heat map
+//!
+//! Usage: `aoc-2022-12-visualize [--image IMAGE] [INPUT]`, the input is found like for the
+//! solution.
+use std::path::PathBuf;
+
+use aoc_2022_12::{
+    render,
+    terrain::{Climb, Terrain},
+    Day12,
+};
+use aoc_runner::{input, Solution};
+use clap::Parser;
+use color_eyre::{eyre::WrapErr, Result};
+
+/// Pixels per point of the height map in the image
+const SCALE: usize = 4;
+
+/// Print the shortest path of day 12 and save the distances as a heat map
+#[derive(Debug, Parser)]
+struct Args {
+    /// Save the heat map here, as a PPM image with a `.ppm` extension and PNG otherwise
+    #[arg(short, long, default_value = "distances.png")]
+    image: PathBuf,
+    /// Read the input from this file (`-` for stdin) instead of the default location
+    input: Option<PathBuf>,
+}
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+
+    let Args { image, input } = Args::parse();
+    let source = input.map(|path| input::Source::from(path.as_os_str()));
+
+    let input = input::load(Day12::YEAR, Day12::DAY, source.as_ref())?;
+    let terrain = Terrain::new(Day12::parse(input)?);
+
+    let path = terrain.path_from_start(&Climb);
+    match &path {
+        Some(path) => {
+            print!("{}", render::arrows(&terrain, &path.nodes));
+            println!("Reached the end in {} steps", path.cost);
+        }
+        None => {
+            print!("{terrain}");
+            println!("The end can't be reached from the start");
+        }
+    }
+
+    let nodes = path.map(|path| path.nodes).unwrap_or_default();
+    render::heat_map(&terrain, &terrain.distances(&Climb), &nodes)
+        .scaled(SCALE)
+        .save(&image)
+        .wrap_err_with(|| format!("couldn't save the heat map to {}", image.display()))?;
+    println!("Heat map saved to {}", image.display());
+
+    Ok(())
+}
@@ -0,0 +1,346 @@
+//! Why two packets are in the right order or not, step by step
+//!
+//! [`explain`] compares two packets like [`Packet::compare_signal`] but records every step,
+//! and the [`Display`] of the [`Explanation`] is the indented walkthrough from the puzzle text.
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Formatter},
+};
+
+use num::BigUint;
+
+use crate::packet::{Packet, Value};
+
+/// Something compared while walking the packets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Item<'a> {
+    Integer(&'a BigUint),
+    List(&'a [Value]),
+    /// An integer turned into a list with only that integer
+    Promoted(&'a BigUint),
+}
+
+impl<'a> From<&'a Value> for Item<'a> {
+    fn from(value: &'a Value) -> Self {
+        match value {
+            Value::List(list) => Item::List(&list.0),
+            Value::Integer(n) => Item::Integer(n),
+        }
+    }
+}
+
+impl<'a> Item<'a> {
+    /// The items of a list, `None` for an integer
+    fn items(self) -> Option<Vec<Item<'a>>> {
+        match self {
+            Item::Integer(_) => None,
+            Item::List(values) => Some(values.iter().map(Item::from).collect()),
+            Item::Promoted(n) => Some(vec![Item::Integer(n)]),
+        }
+    }
+}
+
+impl Display for Item<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Item::Integer(n) => write!(f, "{n}"),
+            Item::List(values) => {
+                write!(f, "[")?;
+                for (ix, value) in values.iter().enumerate() {
+                    if ix > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                write!(f, "]")
+            }
+            Item::Promoted(n) => write!(f, "[{n}]"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+impl Side {
+    fn name(self) -> &'static str {
+        match self {
+            Side::Left => "left",
+            Side::Right => "right",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reason {
+    /// Two integers differ
+    Smaller(Side),
+    /// All the items matched and one of the lists is shorter
+    RanOut(Side),
+}
+
+impl Reason {
+    pub fn in_order(self) -> bool {
+        matches!(
+            self,
+            Reason::Smaller(Side::Left) | Reason::RanOut(Side::Left)
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step<'a> {
+    Compare {
+        left: Item<'a>,
+        right: Item<'a>,
+    },
+    /// The integer on one side was compared to a list and becomes a list
+    Promote {
+        side: Side,
+        to: Item<'a>,
+    },
+    /// The deciding step, always the last one
+    Decide(Reason),
+}
+
+impl Display for Step<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Step::Compare { left, right } => write!(f, "Compare {left} vs {right}"),
+            Step::Promote { side, to } => write!(
+                f,
+                "Mixed types; convert {} to {to} and retry comparison",
+                side.name()
+            ),
+            Step::Decide(reason) => {
+                let (side, why) = match reason {
+                    Reason::Smaller(side) => (side, "is smaller"),
+                    Reason::RanOut(side) => (side, "ran out of items"),
+                };
+                let side = match side {
+                    Side::Left => "Left",
+                    Side::Right => "Right",
+                };
+                let order = if reason.in_order() { "" } else { "not " };
+                write!(
+                    f,
+                    "{side} side {why}, so inputs are {order}in the right order"
+                )
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TracedStep<'a> {
+    /// Index of the items compared in each enclosing list, promoted ones included
+    pub path: Vec<usize>,
+    /// Indentation in the walkthrough
+    pub depth: usize,
+    pub step: Step<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation<'a> {
+    pub steps: Vec<TracedStep<'a>>,
+    pub ordering: Ordering,
+}
+
+impl<'a> Explanation<'a> {
+    /// The step deciding the order, `None` if the packets are equal
+    pub fn deciding_step(&self) -> Option<&TracedStep<'a>> {
+        self.steps
+            .last()
+            .filter(|last| matches!(last.step, Step::Decide(_)))
+    }
+
+    pub fn in_order(&self) -> bool {
+        self.ordering.is_lt()
+    }
+}
+
+impl Display for Explanation<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for step in &self.steps {
+            writeln!(f, "{:indent$}- {}", "", step.step, indent = 2 * step.depth)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compare `left` to `right` like [`Packet::compare_signal`], recording each step
+pub fn explain<'a>(left: &'a Packet, right: &'a Packet) -> Explanation<'a> {
+    let mut steps = vec![];
+    let ordering = compare(
+        Item::List(&left.0),
+        Item::List(&right.0),
+        0,
+        &mut vec![],
+        &mut steps,
+    );
+    Explanation { steps, ordering }
+}
+
+fn compare<'a>(
+    left: Item<'a>,
+    right: Item<'a>,
+    depth: usize,
+    path: &mut Vec<usize>,
+    steps: &mut Vec<TracedStep<'a>>,
+) -> Ordering {
+    let mut trace = |depth, step| {
+        steps.push(TracedStep {
+            path: path.clone(),
+            depth,
+            step,
+        })
+    };
+    trace(depth, Step::Compare { left, right });
+
+    let (left_items, right_items) = match (left, right) {
+        (Item::Integer(l), Item::Integer(r)) => {
+            let ordering = l.cmp(r);
+            match ordering {
+                Ordering::Less => trace(depth + 1, Step::Decide(Reason::Smaller(Side::Left))),
+                Ordering::Greater => trace(depth + 1, Step::Decide(Reason::Smaller(Side::Right))),
+                Ordering::Equal => {}
+            }
+            return ordering;
+        }
+        (Item::Integer(n), _) => {
+            let to = Item::Promoted(n);
+            trace(
+                depth + 1,
+                Step::Promote {
+                    side: Side::Left,
+                    to,
+                },
+            );
+            return compare(to, right, depth + 1, path, steps);
+        }
+        (_, Item::Integer(n)) => {
+            let to = Item::Promoted(n);
+            trace(
+                depth + 1,
+                Step::Promote {
+                    side: Side::Right,
+                    to,
+                },
+            );
+            return compare(left, to, depth + 1, path, steps);
+        }
+        (left, right) => (
+            left.items().expect("left is a list"),
+            right.items().expect("right is a list"),
+        ),
+    };
+
+    for (ix, (&l, &r)) in left_items.iter().zip(&right_items).enumerate() {
+        path.push(ix);
+        let ordering = compare(l, r, depth + 1, path, steps);
+        path.pop();
+        if ordering.is_ne() {
+            return ordering;
+        }
+    }
+
+    let ordering = left_items.len().cmp(&right_items.len());
+    let side = match ordering {
+        Ordering::Less => Side::Left,
+        Ordering::Greater => Side::Right,
+        Ordering::Equal => return ordering,
+    };
+    steps.push(TracedStep {
+        path: path.clone(),
+        depth: depth + 1,
+        step: Step::Decide(Reason::RanOut(side)),
+    });
+    ordering
+}
+
+/// The walkthrough of every pair, like in the puzzle text
+pub fn walkthrough(pairs: &[(Packet, Packet)]) -> String {
+    pairs
+        .iter()
+        .enumerate()
+        .map(|(ix, (left, right))| format!("== Pair {} ==\n{}", ix + 1, explain(left, right)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use std::cmp::Ordering;
+
+    use crate::{
+        explain::{explain, walkthrough, Item, Reason, Side, Step},
+        parse, Packet,
+    };
+    use color_eyre::Result;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    static INPUT: &str = include_str!("test_input");
+    static WALKTHROUGH: &str = include_str!("test_walkthrough");
+
+    #[test]
+    fn puzzle_walkthrough() -> Result<()> {
+        let pairs = parse::input(INPUT)?;
+        assert_eq!(walkthrough(&pairs), WALKTHROUGH);
+
+        for (left, right) in &pairs {
+            let explanation = explain(left, right);
+            assert_eq!(explanation.ordering, left.compare_signal(right));
+            assert_eq!(explanation.in_order(), left.compare_signal(right).is_lt());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn structured_steps() -> Result<()> {
+        let left: Packet = "[[1],[2,3,4]]".parse()?;
+        let right: Packet = "[[1],4]".parse()?;
+        let explanation = explain(&left, &right);
+
+        let promote = &explanation.steps[4];
+        assert_eq!(promote.path, [1]);
+        assert!(matches!(
+            promote.step,
+            Step::Promote {
+                side: Side::Right,
+                to: Item::Promoted(_)
+            }
+        ));
+
+        let decision = explanation.deciding_step().unwrap();
+        assert_eq!(decision.path, [1, 0]);
+        assert_eq!(decision.depth, 4);
+        assert_eq!(decision.step, Step::Decide(Reason::Smaller(Side::Left)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn equal_packets() -> Result<()> {
+        let left: Packet = "[[2]]".parse()?;
+        let right: Packet = "[2]".parse()?;
+        let explanation = explain(&left, &right);
+
+        assert_eq!(explanation.ordering, Ordering::Equal);
+        assert_eq!(explanation.deciding_step(), None);
+        assert_eq!(
+            explanation.to_string(),
+            "- Compare [[2]] vs [2]\n  \
+             - Compare [2] vs 2\n    \
+             - Mixed types; convert right to [2] and retry comparison\n    \
+             - Compare [2] vs [2]\n      \
+             - Compare 2 vs 2\n"
+        );
+
+        Ok(())
+    }
+}
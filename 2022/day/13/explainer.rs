@@ -0,0 +1,19 @@
+//! Print why each pair of packets is in the right order or not, like the puzzle text
+//!
+//! Usage: `aoc-2022-13-explain [INPUT]`, the input is found like for the solution.
+use aoc_2022_13::{explain, Day13};
+use aoc_runner::{input, Solution};
+use color_eyre::Result;
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+
+    let source = std::env::args_os()
+        .nth(1)
+        .map(|arg| input::Source::from(arg.as_os_str()));
+    let input = input::load(Day13::YEAR, Day13::DAY, source.as_ref())?;
+
+    print!("{}", explain::walkthrough(&Day13::parse(input)?));
+
+    Ok(())
+}
@@ -0,0 +1,259 @@
+use aoc_runner::Solution;
+use color_eyre::{Report, Result};
+pub use packet::{List, Packet, Value};
+
+pub mod explain;
+pub mod packet;
+pub mod stream;
+
+#[allow(dead_code)]
+fn empty_option_err() -> Report {
+    use std::io::{Error, ErrorKind::Other};
+    Error::new(Other, "Option was empty").into()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 13;
+
+    type Parsed = Vec<(Packet, Packet)>;
+    type Prepared = Vec<(Packet, Packet)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        Ok(parse::input(input)?)
+    }
+
+    fn prepare(packets: Self::Parsed) -> Result<Self::Prepared> {
+        Ok(packets)
+    }
+
+    fn part1(packets: &Self::Prepared) -> Result<Self::Answer1> {
+        Ok(in_order_ix_sum(packets))
+    }
+
+    fn part2(packets: &Self::Prepared) -> Result<Self::Answer2> {
+        Ok(decoder_key(packets, &Packet::dividers()))
+    }
+}
+
+/// Product of the 1-based positions of the `dividers` once sorted with the packets
+///
+/// Counts the packets smaller than each divider in a single pass instead of sorting them.
+pub fn decoder_key(packets: &[(Packet, Packet)], dividers: &[Packet]) -> usize {
+    let mut positions: Vec<usize> = dividers
+        .iter()
+        .map(|divider| {
+            let smaller = dividers
+                .iter()
+                .filter(|other| other.compare_signal(divider).is_lt());
+            1 + smaller.count()
+        })
+        .collect();
+
+    for packet in packets.iter().flat_map(|(left, right)| [left, right]) {
+        for (position, divider) in positions.iter_mut().zip(dividers) {
+            if packet.compare_signal(divider).is_lt() {
+                *position += 1;
+            }
+        }
+    }
+
+    positions.into_iter().product()
+}
+
+/// [`decoder_key`] by sorting the packets together with the dividers
+pub fn decoder_key_sorted(packets: &[(Packet, Packet)], dividers: &[Packet]) -> usize {
+    let mut sorted: Vec<&Packet> = packets
+        .iter()
+        .flat_map(|(left, right)| [left, right])
+        .chain(dividers)
+        .collect();
+    sorted.sort_unstable();
+
+    dividers
+        .iter()
+        .map(|divider| {
+            let ix = sorted
+                .iter()
+                .position(|&packet| packet == divider)
+                .expect("the dividers were sorted with the packets");
+            1 + ix
+        })
+        .product()
+}
+
+fn in_order_ix_sum(packets: &[(Packet, Packet)]) -> usize {
+    packets
+        .iter()
+        .enumerate()
+        .filter(|(_, pair)| in_order(pair))
+        .map(|(ix, _)| ix + 1)
+        .sum()
+}
+
+fn in_order((left, right): &(Packet, Packet)) -> bool {
+    left.compare_signal(right).is_lt()
+}
+
+mod parse {
+    use aoc_runner::parse::{complete, BadInput, IResult};
+    use nom::{
+        branch::alt,
+        character::{
+            self,
+            complete::{line_ending, multispace0},
+            streaming::char,
+        },
+        combinator::{map, map_res},
+        multi::{separated_list0, separated_list1},
+        sequence::{delimited, separated_pair, terminated},
+        Parser,
+    };
+    use nom_supreme::ParserExt;
+
+    use crate::packet::{List, Packet, Value};
+
+    impl Packet {
+        fn parse(input: &str) -> IResult<&str, Self> {
+            map(
+                delimited(
+                    char('['),
+                    separated_list0(char(','), Value::parse),
+                    char(']'),
+                ),
+                Self,
+            )
+            .context("list")
+            .parse(input)
+        }
+    }
+
+    impl Value {
+        fn parse(input: &str) -> IResult<&str, Self> {
+            alt((
+                map_res(character::complete::digit1, str::parse).map(Value::Integer),
+                map(List::parse, Self::List),
+            ))(input)
+        }
+    }
+
+    fn packets(input: &str) -> IResult<&str, Vec<(Packet, Packet)>> {
+        terminated(
+            separated_list1(
+                line_ending,
+                terminated(
+                    separated_pair(Packet::parse, line_ending, Packet::parse)
+                        .context("packet pair"),
+                    line_ending,
+                ),
+            ),
+            multispace0,
+        )(input)
+    }
+
+    pub fn input(input: &'static str) -> Result<Vec<(Packet, Packet)>, BadInput> {
+        complete(packets, input)
+    }
+}
+
+aoc_runner::examples! {
+    Day13,
+    "test_input" => { part1: 13, part2: 140 },
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{decoder_key, decoder_key_sorted, parse, stream, List, Packet, Value};
+    use color_eyre::Result;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+    use proptest::{collection::vec, prelude::*, test_runner::FileFailurePersistence};
+
+    static INPUT: &str = include_str!("test_input");
+
+    #[test]
+    fn parsers_agree() -> Result<()> {
+        let pairs: Vec<Packet> = parse::input(INPUT)?
+            .into_iter()
+            .flat_map(|(left, right)| [left, right])
+            .collect();
+        let streamed: Vec<Packet> = stream::packets(INPUT.as_bytes()).collect::<Result<_, _>>()?;
+
+        assert_eq!(pairs, streamed);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_bad_input() {
+        let err = parse::input("[1,[2]\n[3]\n").unwrap_err();
+        let diagnostic = err.diagnostic();
+
+        assert_eq!((diagnostic.line, diagnostic.column), (1, 7));
+        assert_eq!(diagnostic.expected, ["']'"]);
+        assert_eq!(
+            diagnostic.contexts,
+            [(0, "list".to_string()), (0, "packet pair".to_string())]
+        );
+    }
+
+    #[test]
+    fn custom_dividers() -> Result<()> {
+        let pairs = parse::input(INPUT)?;
+        assert_eq!(decoder_key(&pairs, &Packet::dividers()), 140);
+
+        // [[10]] is larger than every packet, [[],[]] only than [] and [[]]
+        let dividers = ["[[10]]".parse()?, "[[],[]]".parse()?, Packet::divider(2)];
+        assert_eq!(decoder_key(&pairs, &dividers), 19 * 3 * 11);
+        assert_eq!(decoder_key_sorted(&pairs, &dividers), 19 * 3 * 11);
+        assert_eq!(decoder_key(&pairs, &[]), 1);
+
+        Ok(())
+    }
+
+    fn value() -> impl Strategy<Value = Value> {
+        (0..12u64)
+            .prop_map(Value::from)
+            .prop_recursive(4, 32, 4, |inner| {
+                vec(inner, 0..4).prop_map(|values| Value::List(List(values)))
+            })
+    }
+
+    fn packet(len: std::ops::Range<usize>) -> impl Strategy<Value = Packet> {
+        vec(value(), len).prop_map(List)
+    }
+
+    proptest! {
+        // Save failures next to lib.rs, by default they go in `2022/day` among the workspace members
+        #![proptest_config(ProptestConfig {
+            failure_persistence: Some(Box::new(FileFailurePersistence::WithSource(
+                "proptest-regressions",
+            ))),
+            ..ProptestConfig::default()
+        })]
+
+        #[test]
+        fn decoder_key_without_sorting(
+            pairs in vec((packet(0..5), packet(0..5)), 0..20),
+            dividers in vec(packet(1..4), 0..4),
+        ) {
+            // Where ties go after sorting is unspecified
+            let packets = pairs.iter().flat_map(|(left, right)| [left, right]);
+            let mut compared = packets.chain(&dividers).flat_map(|packet| {
+                dividers.iter().map(move |divider| (packet, divider))
+            });
+            prop_assume!(compared.all(|(packet, divider)| {
+                std::ptr::eq(packet, divider) || packet.compare_signal(divider).is_ne()
+            }));
+
+            prop_assert_eq!(
+                decoder_key(&pairs, &dividers),
+                decoder_key_sorted(&pairs, &dividers)
+            );
+        }
+    }
+}
@@ -0,0 +1,341 @@
+//! Packets: lists of integers and other lists, nested to any depth
+//!
+//! The integers have no size limit. A packet is written like a JSON array without spaces,
+//! `[1,[2,[]]]`, which is also how [`Display`] writes it, and converts to and from a
+//! [`serde_json::Value`] when it only holds arrays and non-negative integers.
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+use num::{BigUint, ToPrimitive};
+
+use crate::stream::{Decoder, StreamError};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Value {
+    List(List),
+    Integer(BigUint),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct List(pub Vec<Value>);
+
+pub type Packet = List;
+
+impl Packet {
+    /// The divider packets of the puzzle, `[[2]]` and `[[6]]`
+    pub fn dividers() -> [Self; 2] {
+        [Self::divider(2), Self::divider(6)]
+    }
+
+    /// A divider packet, `[[n]]`
+    pub fn divider(n: u64) -> Self {
+        Self(vec![Value::List(Self(vec![n.into()]))])
+    }
+}
+
+impl From<u64> for Value {
+    fn from(n: u64) -> Self {
+        Value::Integer(n.into())
+    }
+}
+
+impl From<BigUint> for Value {
+    fn from(n: BigUint) -> Self {
+        Value::Integer(n)
+    }
+}
+
+impl From<List> for Value {
+    fn from(list: List) -> Self {
+        Value::List(list)
+    }
+}
+
+impl FromIterator<Value> for List {
+    fn from_iter<T: IntoIterator<Item = Value>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+/// Compare the values one by one, the shorter list is smaller if they all match
+fn cmp_lists(left: &[Value], right: &[Value], cmp: fn(&Value, &Value) -> Ordering) -> Ordering {
+    left.iter()
+        .zip(right)
+        .map(|(left, right)| cmp(left, right))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| left.len().cmp(&right.len()))
+}
+
+impl List {
+    /// The order of the puzzle, where an integer compared to a list is a list with only it
+    ///
+    /// Different packets can be equal in this order, like `[[2]]` and `[2]`.
+    pub fn compare_signal(&self, other: &Self) -> Ordering {
+        cmp_lists(&self.0, &other.0, Value::compare_signal)
+    }
+}
+
+impl Value {
+    /// See [`List::compare_signal`]
+    pub fn compare_signal(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Value::List(left), Value::List(right)) => left.compare_signal(right),
+            (Value::List(left), Value::Integer(_)) => {
+                cmp_lists(&left.0, std::slice::from_ref(other), Value::compare_signal)
+            }
+            (Value::Integer(_), Value::List(right)) => {
+                cmp_lists(std::slice::from_ref(self), &right.0, Value::compare_signal)
+            }
+            (Value::Integer(left), Value::Integer(right)) => left.cmp(right),
+        }
+    }
+
+    /// Order values by their shape, integers before lists
+    fn compare_structure(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Value::List(left), Value::List(right)) => {
+                cmp_lists(&left.0, &right.0, Value::compare_structure)
+            }
+            (Value::List(_), Value::Integer(_)) => Ordering::Greater,
+            (Value::Integer(_), Value::List(_)) => Ordering::Less,
+            (Value::Integer(left), Value::Integer(right)) => left.cmp(right),
+        }
+    }
+}
+
+impl PartialOrd for List {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The puzzle order, with its ties broken by [`Value`]'s so it agrees with [`Eq`]
+impl Ord for List {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compare_signal(other)
+            .then_with(|| cmp_lists(&self.0, &other.0, Value::compare_structure))
+    }
+}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The puzzle order, with its ties broken by shape so it agrees with [`Eq`]
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compare_signal(other)
+            .then_with(|| self.compare_structure(other))
+    }
+}
+
+impl Display for List {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+        for (ix, value) in self.0.iter().enumerate() {
+            if ix > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{value}")?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::List(list) => write!(f, "{list}"),
+            Value::Integer(n) => write!(f, "{n}"),
+        }
+    }
+}
+
+/// Parse a single packet, surrounded by any whitespace
+impl FromStr for List {
+    type Err = StreamError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut decoder = Decoder::default();
+        for byte in s.bytes() {
+            if let Some(packet) = decoder.push(byte)? {
+                let rest = s[decoder.offset()..].trim_start();
+                return match rest.chars().next() {
+                    None => Ok(packet),
+                    Some(found) => Err(StreamError::Unexpected {
+                        offset: s.len() - rest.len(),
+                        found,
+                        expected: "the end of the input",
+                    }),
+                };
+            }
+        }
+        decoder.finish()?;
+        Err(StreamError::Empty)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum JsonError {
+    #[error("packets only hold lists and non-negative integers, found {0}")]
+    Unsupported(serde_json::Value),
+    #[error("a packet is a list, found {0}")]
+    NotAList(serde_json::Value),
+    #[error("{0} doesn't fit in a JSON number")]
+    TooBig(BigUint),
+}
+
+impl TryFrom<&serde_json::Value> for Value {
+    type Error = JsonError;
+
+    fn try_from(json: &serde_json::Value) -> Result<Self, Self::Error> {
+        match json {
+            serde_json::Value::Array(_) => List::try_from(json).map(Value::List),
+            serde_json::Value::Number(n) => n
+                .as_u64()
+                .map(Value::from)
+                .ok_or_else(|| JsonError::Unsupported(json.clone())),
+            _ => Err(JsonError::Unsupported(json.clone())),
+        }
+    }
+}
+
+impl TryFrom<&serde_json::Value> for List {
+    type Error = JsonError;
+
+    fn try_from(json: &serde_json::Value) -> Result<Self, Self::Error> {
+        match json {
+            serde_json::Value::Array(values) => values.iter().map(Value::try_from).collect(),
+            _ => Err(JsonError::NotAList(json.clone())),
+        }
+    }
+}
+
+impl TryFrom<&Value> for serde_json::Value {
+    type Error = JsonError;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::List(list) => list.try_into(),
+            Value::Integer(n) => n
+                .to_u64()
+                .map(serde_json::Value::from)
+                .ok_or_else(|| JsonError::TooBig(n.clone())),
+        }
+    }
+}
+
+impl TryFrom<&List> for serde_json::Value {
+    type Error = JsonError;
+
+    fn try_from(list: &List) -> Result<Self, Self::Error> {
+        list.0
+            .iter()
+            .map(serde_json::Value::try_from)
+            .collect::<Result<_, _>>()
+            .map(serde_json::Value::Array)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        cmp::Ordering,
+        collections::{BTreeSet, HashSet},
+    };
+
+    use crate::packet::{JsonError, List, Packet, Value};
+    use color_eyre::Result;
+    use num::BigUint;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+    use serde_json::json;
+
+    #[test]
+    fn display_round_trip() -> Result<()> {
+        for input in ["[]", "[[]]", "[1,[2,[3,[4,[5,6,7]]]],8,9]", "[[4,4],4,4]"] {
+            let packet: Packet = input.parse()?;
+            assert_eq!(packet.to_string(), input);
+        }
+        assert_eq!(Packet::dividers()[1].to_string(), "[[6]]");
+
+        Ok(())
+    }
+
+    #[test]
+    fn big_integers() -> Result<()> {
+        let huge = "123456789012345678901234567890";
+        let packet: Packet = format!("[{huge},[0]]").parse()?;
+        assert_eq!(packet.0[0], Value::Integer(huge.parse::<BigUint>()?));
+
+        let bigger: Packet = format!("[{huge}1]").parse()?;
+        assert_eq!(packet.cmp(&bigger), Ordering::Less);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mixed_comparison() -> Result<()> {
+        let list: Packet = "[[1],[2,3,4]]".parse()?;
+        let int: Packet = "[[1],4]".parse()?;
+        assert!(list < int);
+
+        // [[2]] is [2] compared to 2
+        let nested: Packet = "[[2]]".parse()?;
+        let flat: Packet = "[2]".parse()?;
+        assert_eq!(nested.compare_signal(&flat), Ordering::Equal);
+        assert_ne!(nested, flat);
+
+        Ok(())
+    }
+
+    #[test]
+    fn ord_agrees_with_eq() -> Result<()> {
+        let packets: Vec<Packet> = ["[[2]]", "[2]", "[[[2]]]", "[2]", "[[2],3]", "[1]"]
+            .into_iter()
+            .map(str::parse)
+            .collect::<Result<_, _>>()?;
+
+        // The ties of the puzzle order are broken by shape, integers first
+        let nested: BTreeSet<_> = packets.iter().collect();
+        let hashed: HashSet<_> = packets.iter().collect();
+        assert_eq!(nested.len(), hashed.len());
+        assert_eq!(
+            nested.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            ["[1]", "[2]", "[[2]]", "[[[2]]]", "[[2],3]"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn json() -> Result<()> {
+        let packet: Packet = "[1,[2,[]],3]".parse()?;
+        let value = serde_json::Value::try_from(&packet)?;
+        assert_eq!(value, json!([1, [2, []], 3]));
+        assert_eq!(List::try_from(&value)?, packet);
+
+        assert_eq!(
+            List::try_from(&json!([1, -2])),
+            Err(JsonError::Unsupported(json!(-2)))
+        );
+        assert_eq!(
+            List::try_from(&json!({"a": [1]})),
+            Err(JsonError::NotAList(json!({"a": [1]})))
+        );
+        assert_eq!(Value::try_from(&json!(7))?, Value::from(7));
+
+        let huge: Packet = "[18446744073709551616]".parse()?;
+        assert_eq!(
+            serde_json::Value::try_from(&huge),
+            Err(JsonError::TooBig(BigUint::from(u64::MAX) + 1u32))
+        );
+
+        Ok(())
+    }
+}
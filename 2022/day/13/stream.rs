@@ -0,0 +1,294 @@
+//! Read packets one byte at a time, without holding the whole input in memory
+//!
+//! The [`Decoder`] is fed bytes and hands out each packet as soon as its last `]` arrives.
+//! Packets can be separated by any whitespace, but there can't be any inside a packet.
+use std::io::{self, BufRead};
+
+use num::BigUint;
+
+use crate::packet::{List, Packet, Value};
+
+#[derive(Debug, thiserror::Error)]
+pub enum StreamError {
+    #[error("unexpected {found:?} at byte {offset}, expected {expected}")]
+    Unexpected {
+        offset: usize,
+        found: char,
+        expected: &'static str,
+    },
+    #[error("the input ended inside a packet, at byte {offset}")]
+    UnexpectedEnd { offset: usize },
+    #[error("there is no packet")]
+    Empty,
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+/// The last token read inside a packet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    Open,
+    Comma,
+    Digit,
+    Close,
+}
+
+/// Turns bytes into packets
+///
+/// After an error the decoder is in an unspecified state and should be dropped.
+#[derive(Debug, Clone, Default)]
+pub struct Decoder {
+    /// The lists still open, outermost first
+    stack: Vec<Vec<Value>>,
+    /// The digits of the integer being read
+    digits: Vec<u8>,
+    last: Option<Token>,
+    /// Bytes read so far
+    offset: usize,
+}
+
+impl Decoder {
+    /// Bytes read so far
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Read the next byte, returning the packet it completes
+    pub fn push(&mut self, byte: u8) -> Result<Option<Packet>, StreamError> {
+        let offset = self.offset;
+        self.offset += 1;
+        let unexpected = |expected| StreamError::Unexpected {
+            offset,
+            found: char::from(byte),
+            expected,
+        };
+
+        let Some(last) = self.last else {
+            // Between packets
+            return match byte {
+                b'[' => {
+                    self.open();
+                    Ok(None)
+                }
+                _ if byte.is_ascii_whitespace() => Ok(None),
+                _ => Err(unexpected("'['")),
+            };
+        };
+
+        match (byte, last) {
+            (b'0'..=b'9', Token::Open | Token::Comma | Token::Digit) => {
+                self.digits.push(byte);
+                self.last = Some(Token::Digit);
+            }
+            (b'[', Token::Open | Token::Comma) => self.open(),
+            (b',', Token::Digit | Token::Close) => {
+                self.end_integer();
+                self.last = Some(Token::Comma);
+            }
+            (b']', Token::Open | Token::Digit | Token::Close) => {
+                self.end_integer();
+                let list = List(self.stack.pop().expect("a list is open"));
+                match self.stack.last_mut() {
+                    Some(parent) => {
+                        parent.push(Value::List(list));
+                        self.last = Some(Token::Close);
+                    }
+                    None => {
+                        self.last = None;
+                        return Ok(Some(list));
+                    }
+                }
+            }
+            (_, Token::Open) => return Err(unexpected("an integer, '[' or ']'")),
+            (_, Token::Comma) => return Err(unexpected("an integer or '['")),
+            (_, Token::Digit) => return Err(unexpected("a digit, ',' or ']'")),
+            (_, Token::Close) => return Err(unexpected("',' or ']'")),
+        }
+
+        Ok(None)
+    }
+
+    /// The input is over, fails if it ended inside a packet
+    pub fn finish(&self) -> Result<(), StreamError> {
+        match self.last {
+            None => Ok(()),
+            Some(_) => Err(StreamError::UnexpectedEnd {
+                offset: self.offset,
+            }),
+        }
+    }
+
+    fn open(&mut self) {
+        self.stack.push(vec![]);
+        self.last = Some(Token::Open);
+    }
+
+    fn end_integer(&mut self) {
+        if self.digits.is_empty() {
+            return;
+        }
+        let n = BigUint::parse_bytes(&self.digits, 10).expect("only digits were read");
+        self.digits.clear();
+        self.stack
+            .last_mut()
+            .expect("integers are inside a list")
+            .push(Value::Integer(n));
+    }
+}
+
+/// The packets read from `reader`, stopping after the first error
+pub fn packets<R: BufRead>(reader: R) -> Packets<R> {
+    Packets {
+        reader,
+        decoder: Decoder::default(),
+        done: false,
+    }
+}
+
+pub struct Packets<R> {
+    reader: R,
+    decoder: Decoder,
+    done: bool,
+}
+
+impl<R: BufRead> Packets<R> {
+    fn next_packet(&mut self) -> Result<Option<Packet>, StreamError> {
+        loop {
+            let buf = match self.reader.fill_buf() {
+                Ok(buf) => buf,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err.into()),
+            };
+            if buf.is_empty() {
+                self.decoder.finish()?;
+                return Ok(None);
+            }
+
+            let mut read = 0;
+            let mut packet = None;
+            for &byte in buf {
+                read += 1;
+                packet = self.decoder.push(byte)?;
+                if packet.is_some() {
+                    break;
+                }
+            }
+            self.reader.consume(read);
+
+            if packet.is_some() {
+                return Ok(packet);
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Packets<R> {
+    type Item = Result<Packet, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let next = self.next_packet().transpose();
+        self.done = !matches!(next, Some(Ok(_)));
+        next
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::{BufReader, Read};
+
+    use crate::{
+        packet::Packet,
+        stream::{packets, Decoder, StreamError},
+    };
+    use color_eyre::Result;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    static INPUT: &str = include_str!("test_input");
+
+    /// Hands out the bytes a few at a time
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let n = buf.len().min(self.0.len()).min(3);
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_input() -> Result<()> {
+        let read: Vec<Packet> = packets(INPUT.as_bytes()).collect::<Result<_, _>>()?;
+        assert_eq!(read.len(), 16);
+        assert_eq!(read[15].to_string(), "[1,[2,[3,[4,[5,6,0]]]],8,9]");
+
+        let trickled: Vec<Packet> = packets(BufReader::with_capacity(2, Trickle(INPUT.as_bytes())))
+            .collect::<Result<_, _>>()?;
+        assert_eq!(trickled, read);
+
+        Ok(())
+    }
+
+    #[test]
+    fn byte_by_byte() -> Result<()> {
+        let mut decoder = Decoder::default();
+        let mut completed = vec![];
+        for (offset, byte) in b"[10,[]] [2]".iter().enumerate() {
+            if let Some(packet) = decoder.push(*byte)? {
+                completed.push((offset, packet.to_string()));
+            }
+        }
+        decoder.finish()?;
+        assert_eq!(
+            completed,
+            [(6, "[10,[]]".to_string()), (10, "[2]".to_string())]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn errors() {
+        let err = packets("[1,[2]\n[3]\n".as_bytes())
+            .last()
+            .unwrap()
+            .unwrap_err();
+        assert!(
+            matches!(
+                err,
+                StreamError::Unexpected {
+                    offset: 6,
+                    found: '\n',
+                    ..
+                }
+            ),
+            "{err}"
+        );
+        assert_eq!(
+            err.to_string(),
+            "unexpected '\\n' at byte 6, expected ',' or ']'"
+        );
+
+        let mut read = packets("[1]\n[2,".as_bytes());
+        assert!(matches!(read.next(), Some(Ok(_))));
+        assert!(matches!(
+            read.next(),
+            Some(Err(StreamError::UnexpectedEnd { offset: 7 }))
+        ));
+        assert!(read.next().is_none());
+
+        for bad in ["1", "[,]", "[1,]", "[]1", "[[] ]"] {
+            assert!(bad.parse::<Packet>().is_err(), "{bad}");
+        }
+        assert!(matches!("  ".parse::<Packet>(), Err(StreamError::Empty)));
+        assert!(matches!(
+            "[] []".parse::<Packet>(),
+            Err(StreamError::Unexpected { offset: 3, .. })
+        ));
+    }
+}
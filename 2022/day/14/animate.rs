@@ -0,0 +1,170 @@
+//! Animate the cave filling up with sand, in the terminal or saved as images
+//!
+//! Usage: `aoc-2022-14-animate [OPTIONS] [INPUT]`, the input is found like for the solution.
+//! Every frame is cropped to the part of the cave the sand and rocks end up covering.
+use std::{
+    fs::{self, File},
+    io::{BufWriter, Write},
+    path::PathBuf,
+    thread,
+    time::Duration,
+};
+
+use aoc_2022_14::{
+    cave::{Cave, Floor},
+    render, Day14, SOURCE,
+};
+use aoc_runner::{input, Solution};
+use aoc_utils::{grid::Pos, image::GifWriter};
+use clap::{Parser, ValueEnum};
+use color_eyre::{
+    eyre::{ensure, WrapErr},
+    Result,
+};
+
+/// Animate the sand falling into the cave of day 14
+#[derive(Debug, Parser)]
+struct Args {
+    /// The cave of this part, part 2 has an infinite floor
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Grains of sand dropped between two frames
+    #[arg(short, long, default_value_t = 1)]
+    grains: usize,
+    /// Frames per second, in the terminal and in GIFs
+    #[arg(long, default_value_t = 20.0)]
+    fps: f64,
+    /// Save the frames as an animated GIF for a `.gif` file, as numbered images in this
+    /// directory otherwise
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+    /// Format of the numbered images
+    #[arg(long, value_enum, default_value_t = Format::Png)]
+    format: Format,
+    /// Pixels per position of the cave in images
+    #[arg(long, default_value_t = 4)]
+    scale: usize,
+    /// Read the input from this file (`-` for stdin) instead of the default location
+    input: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    Ppm,
+    Png,
+}
+
+enum Output {
+    Terminal { delay: Duration },
+    Gif(GifWriter<BufWriter<File>>),
+    Frames { dir: PathBuf, format: Format },
+}
+
+impl Output {
+    fn new(args: &Args, region: (Pos, Pos)) -> Result<Self> {
+        let delay = Duration::from_secs_f64(1.0 / args.fps);
+        let Some(output) = &args.output else {
+            return Ok(Output::Terminal { delay });
+        };
+
+        if output.extension().is_some_and(|ext| ext == "gif") {
+            let (min, max) = region;
+            let file = File::create(output)
+                .wrap_err_with(|| format!("couldn't create {}", output.display()))?;
+            let gif = GifWriter::new(
+                BufWriter::new(file),
+                (max.x - min.x + 1) as usize * args.scale,
+                (max.y - min.y + 1) as usize * args.scale,
+                delay,
+            )?;
+            Ok(Output::Gif(gif))
+        } else {
+            fs::create_dir_all(output)
+                .wrap_err_with(|| format!("couldn't create {}", output.display()))?;
+            Ok(Output::Frames {
+                dir: output.clone(),
+                format: args.format,
+            })
+        }
+    }
+
+    fn write(&mut self, cave: &Cave, region: (Pos, Pos), scale: usize, frame: usize) -> Result<()> {
+        match self {
+            Output::Terminal { delay } => {
+                // Clear the screen before drawing over it
+                print!("\x1b[H\x1b[2J{}", render::text(cave, region));
+                println!("{} grains of sand", cave.grains());
+                std::io::stdout().flush()?;
+                thread::sleep(*delay);
+            }
+            Output::Gif(gif) => gif.write_frame(&render::image(cave, region).scaled(scale))?,
+            Output::Frames { dir, format } => {
+                let extension = match format {
+                    Format::Ppm => "ppm",
+                    Format::Png => "png",
+                };
+                let path = dir.join(format!("frame-{frame:05}.{extension}"));
+                render::image(cave, region)
+                    .scaled(scale)
+                    .save(&path)
+                    .wrap_err_with(|| format!("couldn't save {}", path.display()))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<()> {
+        if let Output::Gif(gif) = self {
+            gif.finish()?.flush()?;
+        }
+        Ok(())
+    }
+}
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+
+    let args = Args::parse();
+    ensure!(
+        args.fps.is_finite() && args.fps > 0.0,
+        "the frame rate has to be positive"
+    );
+    ensure!(
+        args.grains > 0,
+        "at least one grain has to fall every frame"
+    );
+    ensure!(args.scale > 0, "the scale has to be at least 1");
+
+    let source = args
+        .input
+        .as_ref()
+        .map(|path| input::Source::from(path.as_os_str()));
+    let input = input::load(Day14::YEAR, Day14::DAY, source.as_ref())?;
+    let paths = Day14::parse(input)?;
+
+    let floor = match args.part {
+        1 => Floor::Abyss,
+        _ => Floor::Infinite,
+    };
+    let mut cave = Cave::new(&paths, SOURCE, floor);
+
+    // Run it once to know how much room the sand takes
+    let mut finished = cave.clone();
+    finished.deposit_all_the_sand();
+    let region = render::active_region(&finished);
+
+    let mut output = Output::new(&args, region)?;
+    let mut falling = true;
+    for frame in 0.. {
+        output.write(&cave, region, args.scale, frame)?;
+        if !falling {
+            break;
+        }
+        falling = render::advance(&mut cave, args.grains);
+    }
+    output.finish()?;
+
+    println!("{} grains of sand came to rest", cave.grains());
+
+    Ok(())
+}
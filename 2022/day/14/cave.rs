@@ -0,0 +1,242 @@
+//! The cave filling up with sand, one grain at a time
+//!
+//! Grains follow the same path as the previous one until the spot it came to rest, so the
+//! [`Cave`] keeps that path and drops the next grain from the end of it instead of the source.
+use std::fmt::{Display, Write};
+
+use aoc_utils::{
+    grid::{Grid, Pos},
+    vector::Vec2,
+};
+use itertools::Itertools;
+
+use crate::{maximum_dimentions, parse::Path};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl Cell {
+    pub fn as_char(&self) -> char {
+        match self {
+            Cell::Air => '.',
+            Cell::Rock => '#',
+            Cell::Sand => 'o',
+        }
+    }
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_char(self.as_char())
+    }
+}
+
+/// What is below the lowest rock
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Floor {
+    /// Nothing, sand falling past the lowest rock falls forever
+    Abyss,
+    /// A floor two rows below the lowest rock, going on forever to both sides
+    Infinite,
+}
+
+/// Where the next step of a grain takes it
+enum Fall {
+    To(Pos),
+    Rest,
+    Abyss,
+}
+
+#[derive(Debug, Clone)]
+pub struct Cave {
+    /// Grows to the sides to follow the sand along an infinite floor
+    cells: Grid<Cell>,
+    source: Pos,
+    /// Row of the infinite floor
+    floor: Option<isize>,
+    /// The path of the last grain from the source, without the spot it came to rest
+    path: Vec<Pos>,
+    grains: usize,
+}
+
+impl Cave {
+    pub fn new(paths: &[Path], source: Pos, floor: Floor) -> Self {
+        let (min, max) = maximum_dimentions(paths);
+        let (min, max) = (min.componentwise_min(source), max.componentwise_max(source));
+        let floor = match floor {
+            Floor::Abyss => None,
+            Floor::Infinite => Some(max.y + 2),
+        };
+
+        let mut cells = Grid::with_bounds(min, Vec2::new(max.x, floor.unwrap_or(max.y)), Cell::Air);
+        for path in paths {
+            for (&from, &to) in path.iter().tuple_windows() {
+                let (min, max) = (from.componentwise_min(to), from.componentwise_max(to));
+                for x in min.x..=max.x {
+                    for y in min.y..=max.y {
+                        cells[Vec2::new(x, y)] = Cell::Rock;
+                    }
+                }
+            }
+        }
+
+        let mut cave = Self {
+            cells,
+            source,
+            floor,
+            path: vec![],
+            grains: 0,
+        };
+        cave.draw_floor();
+        cave
+    }
+
+    /// Grains of sand at rest
+    pub fn grains(&self) -> usize {
+        self.grains
+    }
+
+    pub fn grid(&self) -> &Grid<Cell> {
+        &self.cells
+    }
+
+    pub fn source(&self) -> Pos {
+        self.source
+    }
+
+    /// Row of the infinite floor, if there is one
+    pub fn floor(&self) -> Option<isize> {
+        self.floor
+    }
+
+    /// Where the next grain falls through before it reaches the spot the last one left free
+    pub fn path(&self) -> &[Pos] {
+        &self.path
+    }
+
+    /// Drop grains until one falls into the abyss or the source is covered
+    pub fn deposit_all_the_sand(&mut self) -> usize {
+        while self.deposit_sand().is_some() {}
+        self.grains
+    }
+
+    /// Drop a grain of sand, `None` if it falls into the abyss or the source is covered
+    pub fn deposit_sand(&mut self) -> Option<Pos> {
+        if self.path.is_empty() {
+            if self.cells[self.source] != Cell::Air {
+                return None;
+            }
+            self.path.push(self.source);
+        }
+
+        loop {
+            let pos = *self.path.last()?;
+            match self.fall(pos) {
+                Fall::To(next) => self.path.push(next),
+                Fall::Rest => {
+                    self.path.pop();
+                    self.cells[pos] = Cell::Sand;
+                    self.grains += 1;
+                    return Some(pos);
+                }
+                Fall::Abyss => return None,
+            }
+        }
+    }
+
+    fn fall(&mut self, pos: Pos) -> Fall {
+        // Straight down, then to the left and finally to the right
+        for dx in [0, -1, 1] {
+            let next = pos + Vec2::new(dx, 1);
+            if Some(next.y) == self.floor {
+                continue;
+            }
+            if !self.cells.contains(next) {
+                if self.floor.is_none() {
+                    return Fall::Abyss;
+                }
+                // Only the sides are missing, the grid reaches down to the floor
+                self.cells.grow_to(next, Cell::Air);
+                self.draw_floor();
+            }
+            if self.cells[next] == Cell::Air {
+                return Fall::To(next);
+            }
+        }
+        Fall::Rest
+    }
+
+    fn draw_floor(&mut self) {
+        if let Some(floor) = self.floor {
+            self.cells
+                .row_mut(floor)
+                .expect("the floor is the last row")
+                .fill(Cell::Rock);
+        }
+    }
+}
+
+impl Display for Cave {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        cave::{Cave, Cell, Floor},
+        parse, SOURCE,
+    };
+    use aoc_utils::vector::Vec2;
+    use color_eyre::Result;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    static INPUT: &str = include_str!("test_input");
+
+    #[test]
+    fn resume_from_the_path() -> Result<()> {
+        let paths = parse::input(INPUT)?.1;
+        let mut cave = Cave::new(&paths, SOURCE, Floor::Abyss);
+
+        assert_eq!(cave.deposit_sand(), Some(Vec2::new(500, 8)));
+        assert_eq!(cave.path().len(), 8);
+        assert_eq!(cave.deposit_sand(), Some(Vec2::new(499, 8)));
+        assert_eq!(cave.path().last(), Some(&Vec2::new(500, 7)));
+        assert_eq!(cave.grains(), 2);
+
+        assert_eq!(cave.deposit_all_the_sand(), 24);
+        // The grain falling into the abyss stays where it was last seen
+        assert_eq!(cave.deposit_sand(), None);
+        assert_eq!(cave.grains(), 24);
+
+        Ok(())
+    }
+
+    #[test]
+    fn floor_goes_on_forever() -> Result<()> {
+        // A single rock right below the source
+        let paths = vec![vec![Vec2::new(500, 1), Vec2::new(500, 1)]];
+        let mut cave = Cave::new(&paths, SOURCE, Floor::Infinite);
+        assert_eq!(cave.grid().width(), 1);
+
+        assert_eq!(cave.deposit_all_the_sand(), 8);
+        assert_eq!(
+            cave.to_string(),
+            "..o..
+.o#o.
+ooooo
+#####
+"
+        );
+        assert_eq!(cave.grid()[SOURCE], Cell::Sand);
+        assert_eq!(cave.deposit_sand(), None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+use aoc_runner::Solution;
+use aoc_utils::{grid::Pos, vector::Vec2};
+use cave::{Cave, Floor};
+use color_eyre::{Report, Result};
+pub use parse::Path;
+
+pub mod cave;
+pub mod render;
+
+#[allow(dead_code)]
+fn empty_option_err() -> Report {
+    use std::io::{Error, ErrorKind::Other};
+    Error::new(Other, "Option was empty").into()
+}
+
+/// Where the sand comes from
+pub const SOURCE: Pos = Vec2::new(500, 0);
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 14;
+
+    type Parsed = Vec<parse::Path>;
+    type Prepared = Vec<parse::Path>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        Ok(aoc_runner::parse::complete(parse::input, input)?)
+    }
+
+    fn prepare(paths: Self::Parsed) -> Result<Self::Prepared> {
+        Ok(paths)
+    }
+
+    fn part1(paths: &Self::Prepared) -> Result<Self::Answer1> {
+        Ok(Cave::new(paths, SOURCE, Floor::Abyss).deposit_all_the_sand())
+    }
+
+    fn part2(paths: &Self::Prepared) -> Result<Self::Answer2> {
+        Ok(Cave::new(paths, SOURCE, Floor::Infinite).deposit_all_the_sand())
+    }
+}
+
+fn maximum_dimentions(paths: &[parse::Path]) -> (Pos, Pos) {
+    paths
+        .iter()
+        .flatten()
+        .fold((SOURCE, SOURCE), |(min, max), &point| {
+            (min.componentwise_min(point), max.componentwise_max(point))
+        })
+}
+
+mod parse {
+    use aoc_runner::parse::IResult;
+    use aoc_utils::{grid::Pos, vector::vec2};
+    use nom::{
+        character::complete::{line_ending, u32},
+        combinator::eof,
+        multi::{many1, separated_list1},
+        sequence::terminated,
+        Parser,
+    };
+    use nom_supreme::tag::complete::tag;
+
+    pub type Path = Vec<Pos>;
+
+    pub fn input(input: &str) -> IResult<&str, Vec<Path>> {
+        terminated(
+            many1(terminated(
+                separated_list1(tag(" -> "), vec2(u32.map(|n| n as isize))),
+                line_ending,
+            )),
+            eof,
+        )(input)
+    }
+}
+
+aoc_runner::examples! {
+    Day14,
+    "test_input" => { part1: 24, part2: 93 },
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        cave::{Cave, Floor},
+        maximum_dimentions, parse, SOURCE,
+    };
+    use aoc_utils::vector::Vec2;
+    use color_eyre::Result;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    static INPUT: &str = include_str!("test_input");
+
+    #[test]
+    fn test_input_bounds() -> Result<()> {
+        let data = parse::input(INPUT)?.1;
+
+        let dimentions = maximum_dimentions(&data);
+        assert_eq!(dimentions, (Vec2::new(494, 0), Vec2::new(503, 9)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn infinite_floor_grid() -> Result<()> {
+        let paths = parse::input(INPUT)?.1;
+
+        let mut cave = Cave::new(&paths, SOURCE, Floor::Infinite);
+        cave.deposit_all_the_sand();
+        // Only as wide as the sand got
+        assert_eq!(
+            format!("{cave}"),
+            "..........o..........
+.........ooo.........
+........ooooo........
+.......ooooooo.......
+......oo#ooo##o......
+.....ooo#ooo#ooo.....
+....oo###ooo#oooo....
+...oooo.oooo#ooooo...
+..oooooooooo#oooooo..
+.ooo#########ooooooo.
+ooooo.......ooooooooo
+#####################
+"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+//! Frames of the cave filling up with sand, as text like in the puzzle or as images
+use aoc_utils::{
+    grid::{Grid, Pos},
+    image::{Image, Rgb},
+    vector::Vec2,
+};
+
+use crate::cave::{Cave, Cell};
+
+const AIR: Rgb = [24, 20, 28];
+const ROCK: Rgb = [112, 104, 96];
+const SAND: Rgb = [226, 188, 96];
+const FALLING: Rgb = [250, 236, 180];
+const SOURCE: Rgb = [220, 60, 60];
+
+/// The corners of the part of the cave with rock or sand, and the source
+///
+/// The infinite floor only adds its row, it would be as wide as the whole cave otherwise.
+pub fn active_region(cave: &Cave) -> (Pos, Pos) {
+    let floor = cave.floor();
+    let (min, max) = cave
+        .grid()
+        .iter()
+        .filter(|&(pos, &cell)| cell != Cell::Air && Some(pos.y) != floor)
+        .fold((cave.source(), cave.source()), |(min, max), (pos, _)| {
+            (min.componentwise_min(pos), max.componentwise_max(pos))
+        });
+
+    match floor {
+        Some(floor) => (min, Vec2::new(max.x, floor)),
+        None => (min, max),
+    }
+}
+
+/// The cave from `min` to `max` like in the puzzle, `+` is the source and `~` the path of
+/// the next grain
+pub fn text(cave: &Cave, (min, max): (Pos, Pos)) -> Grid<char> {
+    let mut frame = Grid::with_bounds(min, max, '.');
+    for (pos, c) in frame.iter_mut() {
+        *c = match cave.grid().get(pos) {
+            Some(cell) => cell.as_char(),
+            None if Some(pos.y) == cave.floor() => Cell::Rock.as_char(),
+            None => Cell::Air.as_char(),
+        };
+    }
+    for &pos in cave.path() {
+        if let Some(c) = frame.get_mut(pos) {
+            *c = '~';
+        }
+    }
+    if let Some(c) = frame
+        .get_mut(cave.source())
+        .filter(|c| **c != Cell::Sand.as_char())
+    {
+        *c = '+';
+    }
+    frame
+}
+
+/// The same frame as [`text`], one pixel per position
+pub fn image(cave: &Cave, region: (Pos, Pos)) -> Image {
+    Image::from_grid(&text(cave, region), |_, &c| match c {
+        '#' => ROCK,
+        'o' => SAND,
+        '~' => FALLING,
+        '+' => SOURCE,
+        _ => AIR,
+    })
+}
+
+/// Drop up to `grains` grains of sand, `false` once the sand stopped
+pub fn advance(cave: &mut Cave, grains: usize) -> bool {
+    (0..grains).all(|_| cave.deposit_sand().is_some())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        cave::{Cave, Floor},
+        parse,
+        render::{active_region, advance, image, text, SAND},
+        SOURCE,
+    };
+    use aoc_utils::vector::Vec2;
+    use color_eyre::Result;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    static INPUT: &str = include_str!("test_input");
+
+    #[test]
+    fn puzzle_frames() -> Result<()> {
+        let paths = parse::input(INPUT)?.1;
+        let mut cave = Cave::new(&paths, SOURCE, Floor::Abyss);
+        let region = active_region(&cave);
+        assert_eq!(region, (Vec2::new(494, 0), Vec2::new(503, 9)));
+
+        assert!(advance(&mut cave, 5));
+        assert_eq!(
+            text(&cave, region).to_string(),
+            "......+...
+......~...
+......~...
+......~...
+....#.~.##
+....#.~.#.
+..###.~.#.
+.....~o.#.
+....oooo#.
+#########.
+"
+        );
+
+        assert!(!advance(&mut cave, 100));
+        assert_eq!(cave.grains(), 24);
+        let last = text(&cave, region).to_string();
+        assert_eq!(last.lines().nth(2), Some(".....~o..."));
+        assert_eq!(
+            last.lines().nth(8),
+            Some("~o.ooooo#."),
+            "falling into the abyss"
+        );
+
+        let image = image(&cave, region);
+        assert_eq!((image.width(), image.height()), (10, 10));
+        assert_eq!(image.pixels().iter().filter(|&&p| p == SAND).count(), 24);
+
+        Ok(())
+    }
+
+    #[test]
+    fn crop_the_floor() -> Result<()> {
+        let paths = parse::input(INPUT)?.1;
+        let mut cave = Cave::new(&paths, SOURCE, Floor::Infinite);
+        assert_eq!(
+            active_region(&cave),
+            (Vec2::new(494, 0), Vec2::new(503, 11))
+        );
+
+        cave.deposit_all_the_sand();
+        assert_eq!(
+            active_region(&cave),
+            (Vec2::new(490, 0), Vec2::new(510, 11))
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,225 @@
+use aoc_runner::Solution;
+use color_eyre::{Report, Result};
+use itertools::Itertools;
+use parse::SensorBeaconPair;
+use std::{iter::successors, ops::RangeInclusive};
+
+#[allow(dead_code)]
+fn empty_option_err() -> Report {
+    use std::io::{Error, ErrorKind::Other};
+    Error::new(Other, "Option was empty").into()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 15;
+
+    type Parsed = Vec<SensorBeaconPair>;
+    type Prepared = Vec<SensorBeaconPair>;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        Ok(aoc_runner::parse::complete(parse::input, input)?)
+    }
+
+    fn prepare(sensors: Self::Parsed) -> Result<Self::Prepared> {
+        Ok(sensors)
+    }
+
+    fn part1(sensors: &Self::Prepared) -> Result<Self::Answer1> {
+        Ok((LazyGrid { sensors }).empty_at_row(2_000_000))
+    }
+
+    fn part2(sensors: &Self::Prepared) -> Result<Self::Answer2> {
+        Ok((LazyGrid { sensors }).avalilable_within_area(4_000_000))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct LazyGrid<'a> {
+    sensors: &'a [SensorBeaconPair],
+}
+
+impl LazyGrid<'_> {
+    fn sensor_cover_ranges_at_row(self, row: i32) -> Vec<RangeInclusive<i32>> {
+        self
+            .sensors
+            .iter()
+            .filter_map(|&SensorBeaconPair { sensor, beacon }| {
+                let distance = sensor.manhattan(beacon);
+
+                if (sensor.y - distance..=sensor.y + distance).contains(&row) {
+                    let dist = (sensor.y - row).abs();
+
+                    let x_min = sensor.x - (distance - dist);
+                    let x_max = sensor.x + (distance - dist);
+
+                    #[cfg(test)]
+                    println!("{sensor:>2?} {beacon:>2?}: {distance:>2} {dist:>2} -> {x_min:>2}..={x_max:2>}");
+
+                    Some((x_min, x_max))
+                } else {
+                    None
+                }
+            })
+            .sorted_unstable_by(|a, b| a.0.cmp(&b.0))
+            .fold(vec![], |mut ranges, (x_min, x_max)| {
+                if let Some((a, b)) = ranges.pop() {
+                    if x_min <= b {
+                        ranges.push((a, b.max(x_max)));
+                    } else {
+                        ranges.push((a, b));
+                        ranges.push((x_min, x_max));
+                    }
+                } else {
+                    ranges.push((x_min, x_max));
+                }
+                ranges
+            })
+            .into_iter()
+            .map(|(min, max)| min..=max)
+            .collect_vec()
+    }
+
+    fn avalilable_within_area(self, width: i32) -> i64 {
+        for row in 0..=width {
+            if row % 100 == 0 {
+                print!(
+                    "[{row:>w$}/{width}]\r",
+                    w = successors(Some(width), |&n| (n >= 10).then_some(n / 10)).count()
+                );
+            }
+            if let Some(x) = self.available_at_row(row, width) {
+                return x as i64 * 4_000_000 + row as i64;
+            }
+        }
+
+        unreachable!()
+    }
+
+    fn available_at_row(self, row: i32, width: i32) -> Option<i32> {
+        let mut range = self
+            .sensor_cover_ranges_at_row(row)
+            .into_iter()
+            .find(|range| range.contains(&0) || range.contains(&width))?;
+
+        if range.contains(&0) && range.contains(&width) {
+            None
+        } else if range.contains(&0) {
+            range.last().map(|n| n + 1)
+        } else {
+            range.next().map(|n| n - 1)
+        }
+    }
+
+    fn empty_at_row(self, row: i32) -> usize {
+        let ranges = self.sensor_cover_ranges_at_row(row);
+
+        let beacons_on_row = self
+            .sensors
+            .iter()
+            .map(|&SensorBeaconPair { sensor: _, beacon }| beacon)
+            .filter(|beacon| {
+                beacon.y == row && ranges.iter().any(|range| range.contains(&beacon.x))
+            })
+            .unique()
+            .count();
+
+        #[cfg(test)]
+        println!("{ranges:?}");
+
+        ranges.into_iter().map(|range| range.count()).sum::<usize>() - beacons_on_row
+    }
+}
+
+mod parse {
+    use aoc_runner::parse::IResult;
+    use aoc_utils::vector::Vec2;
+    use nom::{
+        character::{self, complete::line_ending},
+        combinator::{eof, map},
+        multi::many1,
+        sequence::{preceded, separated_pair, terminated},
+    };
+    use nom_supreme::tag::complete::tag;
+
+    pub type Coord = Vec2<i32>;
+
+    #[derive(Debug, Clone, Copy)]
+    pub struct SensorBeaconPair {
+        pub sensor: Coord,
+        pub beacon: Coord,
+    }
+
+    impl SensorBeaconPair {
+        fn parse(input: &str) -> IResult<&str, Self> {
+            map(
+                separated_pair(
+                    preceded(
+                        tag("Sensor at "),
+                        separated_pair(
+                            preceded(tag("x="), character::complete::i32),
+                            tag(", "),
+                            preceded(tag("y="), character::complete::i32),
+                        ),
+                    ),
+                    tag(": "),
+                    preceded(
+                        tag("closest beacon is at "),
+                        separated_pair(
+                            preceded(tag("x="), character::complete::i32),
+                            tag(", "),
+                            preceded(tag("y="), character::complete::i32),
+                        ),
+                    ),
+                ),
+                |(sensor, beacon)| SensorBeaconPair {
+                    sensor: sensor.into(),
+                    beacon: beacon.into(),
+                },
+            )(input)
+        }
+    }
+
+    pub fn input(input: &str) -> IResult<&str, Vec<SensorBeaconPair>> {
+        terminated(many1(terminated(SensorBeaconPair::parse, line_ending)), eof)(input)
+    }
+}
+
+aoc_runner::examples! {
+    Day15,
+    "test_input" => {},
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{parse, LazyGrid};
+    use color_eyre::Result;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    static INPUT: &str = include_str!("test_input");
+
+    #[test]
+    fn problem_1() -> Result<()> {
+        let data = parse::input(INPUT)?.1;
+
+        let grid = LazyGrid { sensors: &data };
+        assert_eq!(grid.empty_at_row(10), 26);
+
+        Ok(())
+    }
+
+    #[test]
+    fn problem_2() -> Result<()> {
+        let data = parse::input(INPUT)?.1;
+
+        let grid = LazyGrid { sensors: &data };
+        assert_eq!(grid.avalilable_within_area(20), 56000011);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+use aoc_runner::Solution;
+use color_eyre::{Report, Result};
+use parse::{Valve, ValveLabel};
+use petgraph::{
+    matrix_graph::{MatrixGraph, NodeIndex},
+    Undirected,
+};
+use std::{collections::HashMap, fmt::Debug};
+
+#[allow(dead_code)]
+fn empty_option_err() -> Report {
+    use std::io::{Error, ErrorKind::Other};
+    Error::new(Other, "Option was empty").into()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 16;
+
+    type Parsed = Vec<Valve>;
+    type Prepared = (NodeIndex<u16>, MatrixGraph<Node, f32, Undirected>);
+    type Answer1 = &'static str;
+    type Answer2 = &'static str;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        Ok(aoc_runner::parse::complete(parse::input, input)?)
+    }
+
+    fn prepare(valves: Self::Parsed) -> Result<Self::Prepared> {
+        Ok(as_graph(valves))
+    }
+
+    fn part1(_: &Self::Prepared) -> Result<Self::Answer1> {
+        Ok("Nothing yet")
+    }
+
+    fn part2(_: &Self::Prepared) -> Result<Self::Answer2> {
+        Ok("Nothing yet")
+    }
+}
+
+pub struct Node {
+    label: u16,
+    flow_rate: u32,
+}
+
+impl Debug for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Node {}{} ({})",
+            (self.label / 256) as u8 as char,
+            (self.label % 256) as u8 as char,
+            self.flow_rate
+        )
+    }
+}
+
+fn as_graph(
+    valves: impl IntoIterator<Item = Valve>,
+) -> (NodeIndex<u16>, MatrixGraph<Node, f32, Undirected>) {
+    let mut graph = MatrixGraph::<Node, f32, Undirected>::with_capacity(57);
+    let mut map: HashMap<ValveLabel, _> = HashMap::new();
+
+    for Valve {
+        label,
+        flow_rate,
+        connections,
+    } in valves
+    {
+        let ix = if let Some(ix) = map.get(&label) {
+            *ix
+        } else {
+            let ix = graph.add_node(Node { label, flow_rate });
+            map.insert(label, ix);
+            ix
+        };
+
+        for label in connections {
+            let Some(iy) = map.get(&label) else {
+                continue;
+            };
+
+            graph.update_edge(ix, *iy, 1.0);
+        }
+    }
+
+    (*map.get(&('A' as u16 * 256 + 'A' as u16)).unwrap(), graph)
+}
+
+mod parse {
+    use aoc_runner::parse::IResult;
+    use nom::{
+        character::{
+            self,
+            complete::{line_ending, one_of},
+        },
+        combinator::{eof, map},
+        multi::{many1, separated_list1},
+        sequence::{delimited, pair, preceded, terminated, tuple},
+    };
+    use nom_supreme::tag::complete::tag;
+
+    pub type ValveLabel = u16;
+
+    #[derive(Debug)]
+    pub struct Valve {
+        pub label: ValveLabel,
+        pub flow_rate: u32,
+        pub connections: Vec<ValveLabel>,
+    }
+
+    impl Valve {
+        fn parse(input: &str) -> IResult<&str, Self> {
+            map(
+                tuple((
+                    preceded(
+                        tag("Valve "),
+                        map(
+                            pair(
+                                one_of("ABCDEFGHIJKLMNOPQRSTUVWXYZ"),
+                                one_of("ABCDEFGHIJKLMNOPQRSTUVWXYZ"),
+                            ),
+                            |(a, b)| a as u16 * 256 + b as u16,
+                        ),
+                    ),
+                    delimited(tag(" has flow rate="), character::complete::u32, tag("; ")),
+                    preceded(
+                        nom::branch::alt((
+                            tag("tunnel leads to valve "),
+                            tag("tunnels lead to valves "),
+                        )),
+                        separated_list1(
+                            tag(", "),
+                            map(
+                                pair(
+                                    one_of("ABCDEFGHIJKLMNOPQRSTUVWXYZ"),
+                                    one_of("ABCDEFGHIJKLMNOPQRSTUVWXYZ"),
+                                ),
+                                |(a, b)| a as u16 * 256 + b as u16,
+                            ),
+                        ),
+                    ),
+                )),
+                |(label, flow_rate, connections)| Self {
+                    label,
+                    flow_rate,
+                    connections,
+                },
+            )(input)
+        }
+    }
+
+    pub fn input(input: &str) -> IResult<&str, Vec<Valve>> {
+        terminated(many1(terminated(Valve::parse, line_ending)), eof)(input)
+    }
+}
+
+aoc_runner::examples! {
+    Day16,
+    "test_input" => {},
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{as_graph, parse, Day16};
+    use aoc_runner::Solution;
+    use color_eyre::Result;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    static INPUT: &str = include_str!("test_input");
+
+    #[test]
+    #[ignore = "part 1 is unsolved and always answers \"Nothing yet\""]
+    fn problem_1() -> Result<()> {
+        let valves = parse::input(INPUT)?.1;
+
+        let (start, graph) = as_graph(valves);
+        assert_eq!(Day16::part1(&(start, graph))?, "1651");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,250 @@
+use aoc_runner::Solution;
+use aoc_utils::{
+    grid::{Grid, Pos},
+    vector::Vec2,
+};
+use color_eyre::Result;
+use parse::Direction;
+use std::fmt::Debug;
+
+/// The rocks in the order they fall, separated by empty lines
+static ROCKS: &str = "\
+####
+
+.#.
+###
+.#.
+
+..#
+..#
+###
+
+#
+#
+#
+#
+
+##
+##
+";
+
+fn rocks() -> Vec<Grid<Cell>> {
+    ROCKS
+        .split("\n\n")
+        .map(|rock| Grid::parse_chars(rock, Cell::from_char).expect("the rocks are well formed"))
+        .collect()
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Air,
+    Rock,
+}
+
+impl Cell {
+    const fn as_char(self) -> char {
+        match self {
+            Cell::Air => '.',
+            Cell::Rock => '#',
+        }
+    }
+
+    fn from_char(c: char) -> Result<Self, char> {
+        match c {
+            '.' => Ok(Cell::Air),
+            '#' => Ok(Cell::Rock),
+            c => Err(c),
+        }
+    }
+}
+
+impl Debug for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_char())
+    }
+}
+
+/// The chamber the rocks pile up in, the floor is the row at `y = 0` and it grows upwards
+struct Chamber {
+    cells: Grid<Cell>,
+    /// Row of the highest rock, the floor when there are none
+    top: isize,
+}
+
+impl Debug for Chamber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut rows = self.cells.rows();
+        rows.next_back();
+        for row in rows {
+            write!(f, "|")?;
+            for cell in row {
+                write!(f, "{cell:?}")?;
+            }
+            writeln!(f, "|")?;
+        }
+        write!(f, "+{}+", "-".repeat(self.cells.width()))
+    }
+}
+
+impl Chamber {
+    fn new(width: usize) -> Self {
+        Self {
+            cells: Grid::new(width, 1, Cell::Rock),
+            top: 0,
+        }
+    }
+
+    /// Let the `rock` fall until it comes to rest, returns the index of the next direction
+    fn let_rock_fall(
+        &mut self,
+        rock: &Grid<Cell>,
+        directions: &[Direction],
+        mut dir_ix: usize,
+    ) -> usize {
+        assert!(dir_ix < directions.len());
+
+        // The top left corner of the rock, its bottom row is 3 rows above the highest rock
+        let mut pos = Vec2::new(2, self.top - 3 - rock.height() as isize);
+        self.cells.grow_to(pos, Cell::Air);
+
+        loop {
+            let push = match directions[dir_ix] {
+                Direction::Left => Vec2::new(-1, 0),
+                Direction::Right => Vec2::new(1, 0),
+            };
+            dir_ix = (dir_ix + 1) % directions.len();
+
+            if self.fits(rock, pos + push) {
+                pos += push;
+            }
+
+            let below = pos + Vec2::new(0, 1);
+            if !self.fits(rock, below) {
+                self.settle(rock, pos);
+                return dir_ix;
+            }
+            pos = below;
+        }
+    }
+
+    /// Whether the `rock` with its top left corner at `pos` only covers air
+    fn fits(&self, rock: &Grid<Cell>, pos: Pos) -> bool {
+        rock.iter()
+            .filter(|(_, &cell)| cell == Cell::Rock)
+            .all(|(offset, _)| self.cells.get(pos + offset) == Some(&Cell::Air))
+    }
+
+    fn settle(&mut self, rock: &Grid<Cell>, pos: Pos) {
+        for (offset, &cell) in rock.iter() {
+            if cell == Cell::Rock {
+                self.cells[pos + offset] = Cell::Rock;
+            }
+        }
+        self.top = self.top.min(pos.y);
+    }
+
+    fn rocks_height(&self) -> usize {
+        (-self.top) as usize
+    }
+}
+
+fn final_height(steps: usize, directions: &[Direction]) -> usize {
+    let mut chamber = Chamber::new(7);
+    let mut direction = 0;
+
+    for rock in rocks().iter().cycle().take(steps) {
+        direction = chamber.let_rock_fall(rock, directions, direction);
+    }
+
+    chamber.rocks_height()
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 17;
+
+    type Parsed = Vec<Direction>;
+    type Prepared = Vec<Direction>;
+    type Answer1 = usize;
+    type Answer2 = &'static str;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        Ok(aoc_runner::parse::complete(parse::input, input)?)
+    }
+
+    fn prepare(directions: Self::Parsed) -> Result<Self::Prepared> {
+        Ok(directions)
+    }
+
+    fn part1(directions: &Self::Prepared) -> Result<Self::Answer1> {
+        Ok(final_height(2022, directions))
+    }
+
+    fn part2(_: &Self::Prepared) -> Result<Self::Answer2> {
+        Ok("Nothing yet")
+    }
+}
+
+mod parse {
+    use aoc_runner::parse::IResult;
+    use nom::{
+        branch::alt,
+        character::{complete::multispace0, streaming::char},
+        combinator::{eof, value},
+        multi::many1,
+        sequence::{pair, terminated},
+    };
+
+    #[derive(Debug, Clone, Copy)]
+    pub enum Direction {
+        Left,
+        Right,
+    }
+
+    impl Direction {
+        fn parse(input: &str) -> IResult<&str, Direction> {
+            alt((
+                value(Direction::Left, char('<')),
+                value(Direction::Right, char('>')),
+            ))(input)
+        }
+    }
+
+    pub fn input(input: &str) -> IResult<&str, Vec<Direction>> {
+        terminated(many1(Direction::parse), pair(multispace0, eof))(input)
+    }
+}
+
+aoc_runner::examples! {
+    Day17,
+    "test_input" => { part1: 3068 },
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{parse, rocks, Chamber};
+    use color_eyre::Result;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    static INPUT: &str = include_str!("test_input");
+
+    #[test]
+    fn problem_1_sample() -> Result<()> {
+        let directions = parse::input(INPUT)?.1;
+
+        let mut chamber = Chamber::new(7);
+        let mut direction = 0;
+
+        for rock in rocks().iter().cycle().take(10) {
+            direction = chamber.let_rock_fall(rock, &directions, direction);
+            println!("{chamber:?}\nHeight: {}", chamber.rocks_height());
+        }
+
+        assert_eq!(chamber.rocks_height(), 17);
+
+        Ok(())
+    }
+}
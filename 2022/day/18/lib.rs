@@ -0,0 +1,195 @@
+use aoc_runner::Solution;
+use aoc_utils::vector::Vec3;
+use color_eyre::Result;
+use flagset::{flags, FlagSet};
+use std::collections::HashMap;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 18;
+
+    type Parsed = Vec<Vec3<i32>>;
+    type Prepared = Vec<Vec3<i32>>;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        Ok(aoc_runner::parse::complete(parse::input, input)?)
+    }
+
+    fn prepare(points: Self::Parsed) -> Result<Self::Prepared> {
+        Ok(points)
+    }
+
+    fn part1(points: &Self::Prepared) -> Result<Self::Answer1> {
+        Ok(exposed_faces(points))
+    }
+
+    fn part2(points: &Self::Prepared) -> Result<Self::Answer2> {
+        Ok(exposed_faces_strict(points))
+    }
+}
+
+fn exposed_faces_strict(points: &[Vec3<i32>]) -> isize {
+    const CUBE_WIDTH: usize = 21;
+    let mut volume = vec![vec![vec![Cell::Air; CUBE_WIDTH]; CUBE_WIDTH]; CUBE_WIDTH];
+
+    for point in points {
+        volume[point.x as usize][point.y as usize][point.z as usize] = Cell::Lava;
+    }
+
+    for y in volume[0].iter_mut() {
+        for z in y.iter_mut() {
+            if *z == Cell::Air {
+                *z = Cell::Steam;
+            }
+        }
+    }
+
+    for y in volume[CUBE_WIDTH - 1].iter_mut() {
+        for z in y.iter_mut() {
+            if *z == Cell::Air {
+                *z = Cell::Steam;
+            }
+        }
+    }
+
+    for x in volume.iter_mut() {
+        for z in x[0].iter_mut() {
+            if *z == Cell::Air {
+                *z = Cell::Steam;
+            }
+        }
+
+        for z in x[CUBE_WIDTH - 1].iter_mut() {
+            if *z == Cell::Air {
+                *z = Cell::Steam;
+            }
+        }
+    }
+
+    for x in volume.iter_mut() {
+        for y in x.iter_mut() {
+            if y[0] == Cell::Air {
+                y[0] = Cell::Steam;
+            }
+
+            if y[CUBE_WIDTH - 1] == Cell::Air {
+                y[CUBE_WIDTH - 1] = Cell::Steam;
+            }
+        }
+    }
+
+    todo!()
+}
+
+fn exposed_faces(points: &[Vec3<i32>]) -> isize {
+    let mut cubes: HashMap<_, _> = cube_faces(points);
+
+    let mut exposed = 0;
+
+    for point in points {
+        let faces = cubes.get(point).unwrap();
+        exposed += 6;
+
+        for face in faces.into_iter() {
+            if let Some(faces) = cubes.get_mut(&(*point + face.to_vec3())) {
+                exposed -= 2;
+                *faces -= face.opposing_face();
+                *cubes.get_mut(point).unwrap() -= face;
+            }
+        }
+    }
+
+    exposed
+}
+
+fn cube_faces(points: &[Vec3<i32>]) -> HashMap<Vec3<i32>, FlagSet<Face>> {
+    points
+        .iter()
+        .copied()
+        .map(|p| (p, FlagSet::<Face>::full()))
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Air,
+    Lava,
+    Steam,
+}
+
+flags! {
+    enum Face : u8 {
+        PosX,
+        PosY,
+        PosZ,
+        NegX,
+        NegY,
+        NegZ,
+    }
+}
+
+impl Face {
+    fn opposing_face(self) -> Self {
+        match self {
+            Face::PosX => Face::NegX,
+            Face::PosY => Face::NegY,
+            Face::PosZ => Face::NegZ,
+            Face::NegX => Face::PosX,
+            Face::NegY => Face::PosY,
+            Face::NegZ => Face::PosZ,
+        }
+    }
+    fn to_vec3(self) -> Vec3<i32> {
+        match self {
+            Face::PosX => (1, 0, 0).into(),
+            Face::PosY => (0, 1, 0).into(),
+            Face::PosZ => (0, 0, 1).into(),
+            Face::NegX => (-1, 0, 0).into(),
+            Face::NegY => (0, -1, 0).into(),
+            Face::NegZ => (0, 0, -1).into(),
+        }
+    }
+}
+
+mod parse {
+    use aoc_runner::parse::IResult;
+    use aoc_utils::vector::{vec3, Vec3};
+    use nom::{
+        character::complete::{i32, line_ending, multispace0},
+        multi::separated_list0,
+        sequence::terminated,
+    };
+
+    pub fn input(input: &str) -> IResult<&str, Vec<Vec3<i32>>> {
+        terminated(separated_list0(line_ending, vec3(i32)), multispace0)(input)
+    }
+}
+
+aoc_runner::examples! {
+    Day18,
+    "test_input" => { part1: 64 },
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{exposed_faces_strict, parse};
+    use color_eyre::Result;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    static INPUT: &str = include_str!("test_input");
+
+    #[test]
+    #[ignore = "exposed_faces_strict ends in todo!()"]
+    fn problem_2() -> Result<()> {
+        let points = parse::input(INPUT)?.1;
+
+        assert_eq!(exposed_faces_strict(&points), 58);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use aoc_runner::Solution;
+use color_eyre::{Report, Result};
+
+#[allow(dead_code)]
+fn empty_option_err() -> Report {
+    use std::io::{Error, ErrorKind::Other};
+    Error::new(Other, "Option was empty").into()
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 20;
+
+    type Parsed = Vec<i32>;
+    type Prepared = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = &'static str;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed> {
+        Ok(aoc_runner::parse::complete(parse::input, input)?)
+    }
+
+    fn prepare(coordinates: Self::Parsed) -> Result<Self::Prepared> {
+        Ok(mix(&coordinates))
+    }
+
+    fn part1(mixed: &Self::Prepared) -> Result<Self::Answer1> {
+        Ok(problem_1_result(mixed))
+    }
+
+    fn part2(_: &Self::Prepared) -> Result<Self::Answer2> {
+        Ok("Nothing yet")
+    }
+}
+
+fn problem_1_result(mixed: &[i32]) -> i32 {
+    let zero_pos = mixed.iter().position(|&n| n == 0).unwrap();
+    retrieve_wrapping(mixed, 1000 + zero_pos)
+        + retrieve_wrapping(mixed, 2000 + zero_pos)
+        + retrieve_wrapping(mixed, 3000 + zero_pos)
+}
+
+fn retrieve_wrapping(slice: &[i32], ix: usize) -> i32 {
+    slice[ix % slice.len()]
+}
+
+fn mix(coordinates: &[i32]) -> Vec<i32> {
+    let mut decrypted = coordinates.to_vec();
+    let len = decrypted.len();
+
+    for n in coordinates {
+        let old_ix = decrypted.iter().position(|x| x == n).unwrap();
+        let value = decrypted[old_ix];
+
+        let offset = (value as isize) % len as isize;
+
+        let new_ix = (len + old_ix).checked_add_signed(offset).unwrap();
+        let new_ix = new_ix % len;
+
+        match new_ix.cmp(&old_ix) {
+            std::cmp::Ordering::Less => {
+                if offset.is_negative() {
+                    decrypted.copy_within(new_ix..old_ix, new_ix + 1);
+                } else {
+                    decrypted.copy_within(old_ix + 1..len, old_ix);
+                    decrypted[len - 1] = decrypted[0];
+                    decrypted.copy_within(1..new_ix, 0);
+                }
+            }
+            std::cmp::Ordering::Equal => (),
+            std::cmp::Ordering::Greater => {
+                if offset.is_negative() {
+                    decrypted.copy_within(0..old_ix, 1);
+                    decrypted[0] = decrypted[len - 1];
+                    decrypted.copy_within(new_ix..len - 1, new_ix + 1);
+                } else {
+                    decrypted.copy_within(old_ix + 1..=new_ix, old_ix);
+                }
+            }
+        }
+
+        decrypted[new_ix] = value;
+
+        #[cfg(test)]
+        println!("{decrypted:?}");
+    }
+
+    decrypted
+}
+
+mod parse {
+    use aoc_runner::parse::IResult;
+    use nom::{
+        character::complete::line_ending, combinator::eof, multi::many1, sequence::terminated,
+    };
+
+    pub fn input(input: &str) -> IResult<&str, Vec<i32>> {
+        terminated(
+            many1(terminated(nom::character::complete::i32, line_ending)),
+            eof,
+        )(input)
+    }
+}
+
+aoc_runner::examples! {
+    Day20,
+    "test_input" => { part1: 3 },
+}
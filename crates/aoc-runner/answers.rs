@@ -0,0 +1,206 @@
+//! Known answers for the real inputs, used to catch regressions
+//!
+//! Each day keeps its answers in `YYYY/day/NN/answers.toml`:
+//!
+//! ```toml
+//! part1 = "24000"
+//! part2 = "45000"
+//! ```
+//!
+//! Parts without a recorded answer are reported as [`Status::Unknown`].
+use std::{fmt::Display, path::PathBuf};
+
+use color_eyre::{eyre::WrapErr, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{input, Report};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+/// Result of comparing an answer against the recorded one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+/// The [`Status`] of each part, [`None`] if the part wasn't run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Check {
+    pub part1: Option<Status>,
+    pub part2: Option<Status>,
+}
+
+/// Where the answers for `day` of `year` are stored
+pub fn path(year: u32, day: u32) -> PathBuf {
+    input::day_dir(year, day).join("answers.toml")
+}
+
+impl Answers {
+    /// Read the answers for `day` of `year`, no answers are known if the file doesn't exist
+    pub fn load(year: u32, day: u32) -> Result<Self> {
+        let path = path(year, day);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let answers = std::fs::read_to_string(&path)
+            .wrap_err_with(|| format!("couldn't read answers from {}", path.display()))?;
+        toml::from_str(&answers)
+            .wrap_err_with(|| format!("couldn't parse answers in {}", path.display()))
+    }
+
+    pub fn save(&self, year: u32, day: u32) -> Result<()> {
+        let path = path(year, day);
+        let answers = toml::to_string_pretty(self)?;
+        std::fs::write(&path, answers)
+            .wrap_err_with(|| format!("couldn't write answers to {}", path.display()))
+    }
+
+    /// Compare the answers in `report` to the known ones
+    pub fn check(&self, report: &Report) -> Check {
+        fn status(known: &Option<String>, answer: &str) -> Status {
+            match known {
+                Some(expected) if expected == answer => Status::Pass,
+                Some(expected) => Status::Fail {
+                    expected: expected.clone(),
+                },
+                None => Status::Unknown,
+            }
+        }
+
+        Check {
+            part1: report.part1.as_deref().map(|a| status(&self.part1, a)),
+            part2: report.part2.as_deref().map(|a| status(&self.part2, a)),
+        }
+    }
+
+    /// Take the answers in `report` as the new baseline, parts that weren't run are kept
+    pub fn record(&mut self, report: &Report) {
+        if let Some(answer) = &report.part1 {
+            self.part1 = Some(answer.clone());
+        }
+        if let Some(answer) = &report.part2 {
+            self.part2 = Some(answer.clone());
+        }
+    }
+}
+
+impl Check {
+    pub fn failed(&self) -> bool {
+        [&self.part1, &self.part2]
+            .into_iter()
+            .any(|status| matches!(status, Some(Status::Fail { .. })))
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => f.pad("pass"),
+            Status::Fail { expected } if expected.contains('\n') => {
+                write!(f, "FAIL, expected\n{}", expected.trim_end())
+            }
+            Status::Fail { expected } => f.pad(&format!("FAIL, expected {expected}")),
+            Status::Unknown => f.pad("unknown"),
+        }
+    }
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(status) = &self.part1 {
+            writeln!(f, "Check 1:         {status:>16}")?;
+        }
+        if let Some(status) = &self.part2 {
+            writeln!(f, "Check 2:         {status:>16}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        answers::{Answers, Check, Status},
+        Report,
+    };
+    use color_eyre::Result;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    fn report(part1: Option<&str>, part2: Option<&str>) -> Report {
+        Report {
+            timings: Default::default(),
+            part1: part1.map(str::to_string),
+            part2: part2.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn check_answers() {
+        let answers = Answers {
+            part1: Some("6".to_string()),
+            part2: Some("7".to_string()),
+        };
+
+        assert_eq!(
+            answers.check(&report(Some("6"), Some("8"))),
+            Check {
+                part1: Some(Status::Pass),
+                part2: Some(Status::Fail {
+                    expected: "7".to_string()
+                }),
+            }
+        );
+        assert!(answers.check(&report(Some("6"), Some("8"))).failed());
+        assert!(!answers.check(&report(Some("6"), None)).failed());
+    }
+
+    #[test]
+    fn check_unknown() {
+        let check = Answers::default().check(&report(None, Some("8")));
+
+        assert_eq!(
+            check,
+            Check {
+                part1: None,
+                part2: Some(Status::Unknown),
+            }
+        );
+        assert!(!check.failed());
+    }
+
+    #[test]
+    fn record_keeps_missing_parts() {
+        let mut answers = Answers {
+            part1: Some("6".to_string()),
+            part2: Some("7".to_string()),
+        };
+        answers.record(&report(None, Some("8")));
+
+        assert_eq!(answers.part1.as_deref(), Some("6"));
+        assert_eq!(answers.part2.as_deref(), Some("8"));
+    }
+
+    #[test]
+    fn toml_roundtrip() -> Result<()> {
+        let answers = Answers {
+            part1: Some("6".to_string()),
+            part2: Some("#..#\n.##.\n".to_string()),
+        };
+        let toml = toml::to_string_pretty(&answers)?;
+
+        assert_eq!(toml::from_str::<Answers>(&toml)?, answers);
+        assert_eq!(toml::from_str::<Answers>("part2 = \"1\"")?.part1, None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,280 @@
+//! Run a solution many times and summarize how long each step took
+//!
+//! A single run is too noisy to compare solvers over time, so [`bench`] runs the whole
+//! solution a few times to warm up and then collects [`Stats`] over the measured runs.
+use std::{fmt::Write, time::Duration};
+
+use color_eyre::{eyre::ensure, Result};
+use serde::{Serialize, Serializer};
+
+use crate::{Part, Runner, Timings};
+
+/// Summary of the durations of one step over all the runs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    pub runs: usize,
+    #[serde(rename = "min_ns", serialize_with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "mean_ns", serialize_with = "nanos")]
+    pub mean: Duration,
+    #[serde(rename = "stddev_ns", serialize_with = "nanos")]
+    pub stddev: Duration,
+}
+
+/// The [`Stats`] of every step of a solution, parts that weren't run have none
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Bench {
+    pub parse: Stats,
+    pub prepare: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+    pub total: Stats,
+}
+
+fn nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+impl Stats {
+    /// Compute the statistics of `samples`
+    ///
+    /// # Panics
+    ///
+    /// If there are no samples.
+    pub fn new(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "no samples to compute statistics of");
+        samples.sort_unstable();
+
+        let runs = samples.len();
+        let median = if runs.is_multiple_of(2) {
+            (samples[runs / 2 - 1] + samples[runs / 2]) / 2
+        } else {
+            samples[runs / 2]
+        };
+        let mean = samples.iter().sum::<Duration>() / runs as u32;
+        let variance = if runs > 1 {
+            samples
+                .iter()
+                .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+                .sum::<f64>()
+                / (runs - 1) as f64
+        } else {
+            0.0
+        };
+
+        Self {
+            runs,
+            min: samples[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Run `run` on `input` `warmup` times without measuring, then `runs` times collecting timings
+pub fn bench(
+    run: Runner,
+    input: &'static str,
+    part: Option<Part>,
+    warmup: usize,
+    runs: usize,
+) -> Result<Bench> {
+    ensure!(runs > 0, "at least one run is needed to benchmark");
+
+    for _ in 0..warmup {
+        run(input, part)?;
+    }
+
+    let timings = (0..runs)
+        .map(|_| run(input, part).map(|report| report.timings))
+        .collect::<Result<Vec<_>>>()?;
+    let stats = |step: fn(&Timings) -> Option<Duration>| {
+        let samples: Vec<_> = timings.iter().filter_map(step).collect();
+        (!samples.is_empty()).then(|| Stats::new(samples))
+    };
+
+    Ok(Bench {
+        parse: stats(|t| Some(t.parse)).unwrap_or_default(),
+        prepare: stats(|t| Some(t.prepare)).unwrap_or_default(),
+        part1: stats(|t| t.part1),
+        part2: stats(|t| t.part2),
+        total: stats(|t| Some(t.total)).unwrap_or_default(),
+    })
+}
+
+impl Bench {
+    /// The steps that were measured with their names
+    pub fn steps(&self) -> impl Iterator<Item = (&'static str, Stats)> {
+        [
+            ("parse", Some(self.parse)),
+            ("prepare", Some(self.prepare)),
+            ("part1", self.part1),
+            ("part2", self.part2),
+            ("total", Some(self.total)),
+        ]
+        .into_iter()
+        .filter_map(|(name, stats)| Some((name, stats?)))
+    }
+}
+
+/// One row per year, day and step, durations in nanoseconds
+pub fn to_csv(benches: &[(u32, u32, Bench)]) -> String {
+    let mut csv = "year,day,step,runs,min_ns,median_ns,mean_ns,stddev_ns\n".to_string();
+    for (year, day, bench) in benches {
+        for (step, stats) in bench.steps() {
+            writeln!(
+                csv,
+                "{year},{day},{step},{},{},{},{},{}",
+                stats.runs,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.stddev.as_nanos(),
+            )
+            .expect("writing to a String can't fail");
+        }
+    }
+    csv
+}
+
+/// An array of `{ "year": Y, "day": N, "parse": Stats, ... }` objects, durations in nanoseconds
+pub fn to_json(benches: &[(u32, u32, Bench)]) -> Result<String> {
+    #[derive(Serialize)]
+    struct Day<'a> {
+        year: u32,
+        day: u32,
+        #[serde(flatten)]
+        bench: &'a Bench,
+    }
+
+    let days: Vec<_> = benches
+        .iter()
+        .map(|(year, day, bench)| Day {
+            year: *year,
+            day: *day,
+            bench,
+        })
+        .collect();
+    Ok(serde_json::to_string_pretty(&days)?)
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::{
+        bench::{bench, to_csv, to_json, Bench, Stats},
+        run_part, Part, Solution,
+    };
+    use color_eyre::Result;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    struct Count;
+
+    impl Solution for Count {
+        const YEAR: u32 = 2022;
+        const DAY: u32 = 1;
+
+        type Parsed = usize;
+        type Prepared = usize;
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(input: &'static str) -> Result<Self::Parsed> {
+            Ok(input.len())
+        }
+
+        fn prepare(data: Self::Parsed) -> Result<Self::Prepared> {
+            Ok(data)
+        }
+
+        fn part1(data: &Self::Prepared) -> Result<Self::Answer1> {
+            Ok(*data)
+        }
+
+        fn part2(data: &Self::Prepared) -> Result<Self::Answer2> {
+            Ok(data * 2)
+        }
+    }
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn stats() {
+        let stats = Stats::new(vec![ms(4), ms(1), ms(3), ms(2)]);
+
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        // Sample standard deviation of 1, 2, 3, 4
+        assert_eq!(stats.stddev.as_micros(), 1290);
+    }
+
+    #[test]
+    fn single_sample() {
+        let stats = Stats::new(vec![ms(7)]);
+
+        assert_eq!((stats.min, stats.median, stats.mean), (ms(7), ms(7), ms(7)));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn bench_runs() -> Result<()> {
+        let bench = bench(run_part::<Count>, "abc", Some(Part::One), 2, 5)?;
+
+        assert_eq!(bench.total.runs, 5);
+        assert_eq!(bench.part1.map(|s| s.runs), Some(5));
+        assert_eq!(bench.part2, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_runs() {
+        assert!(bench(run_part::<Count>, "abc", None, 0, 0).is_err());
+    }
+
+    #[test]
+    fn output_formats() -> Result<()> {
+        let stats = Stats::new(vec![ms(1)]);
+        let bench = Bench {
+            parse: stats,
+            prepare: stats,
+            part1: Some(stats),
+            part2: None,
+            total: stats,
+        };
+        // The same day of two years doesn't collide
+        let benches = [(2022, 3, bench), (2023, 3, bench)];
+
+        assert_eq!(
+            to_csv(&benches),
+            "year,day,step,runs,min_ns,median_ns,mean_ns,stddev_ns
+2022,3,parse,1,1000000,1000000,1000000,0
+2022,3,prepare,1,1000000,1000000,1000000,0
+2022,3,part1,1,1000000,1000000,1000000,0
+2022,3,total,1,1000000,1000000,1000000,0
+2023,3,parse,1,1000000,1000000,1000000,0
+2023,3,prepare,1,1000000,1000000,1000000,0
+2023,3,part1,1,1000000,1000000,1000000,0
+2023,3,total,1,1000000,1000000,1000000,0
+"
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&to_json(&benches)?)?;
+        assert_eq!(json[0]["year"], 2022);
+        assert_eq!(json[0]["day"], 3);
+        assert_eq!(json[1]["year"], 2023);
+        assert_eq!(json[0]["part1"]["median_ns"], 1000000);
+        assert_eq!(json[0]["part2"], serde_json::Value::Null);
+
+        Ok(())
+    }
+}
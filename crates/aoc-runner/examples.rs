@@ -0,0 +1,255 @@
+//! Tests generated from the examples in the puzzle text
+//!
+//! A day lists its example files and the answers they should give with [`examples!`],
+//! which generates these tests in an `examples` module:
+//!
+//! - `parse`: every example goes through [`Solution::parse`] and [`Solution::prepare`]
+//! - `parse_input`: the same for the local input, if there is one
+//! - `part1` and `part2`: every example gives the expected answers
+//!
+//! [`Solution::parse`] is expected to reject leftover input (see [`crate::parse::complete`]),
+//! so the parse tests also check that the parser consumes everything.
+//!
+//! [`examples!`]: crate::examples!
+use std::fmt::Display;
+
+use color_eyre::{
+    eyre::{bail, WrapErr},
+    Result,
+};
+
+use crate::{input, run_part, Part, Solution};
+
+/// An example input with the answers it should give, parts without an answer aren't checked
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: &'static str,
+    pub input: &'static str,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Example {
+    pub fn new(name: &'static str, input: &'static str) -> Self {
+        Self {
+            name,
+            input,
+            part1: None,
+            part2: None,
+        }
+    }
+
+    /// Expect `answer` from [`Solution::part1`]
+    pub fn part1(mut self, answer: impl Display) -> Self {
+        self.part1 = Some(answer.to_string());
+        self
+    }
+
+    /// Expect `answer` from [`Solution::part2`]
+    pub fn part2(mut self, answer: impl Display) -> Self {
+        self.part2 = Some(answer.to_string());
+        self
+    }
+
+    pub fn expected(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+fn prepare<S: Solution>(input: &'static str) -> Result<S::Prepared> {
+    S::prepare(S::parse(input)?)
+}
+
+/// Parse and prepare all the examples
+pub fn check_parse<S: Solution>(examples: &[Example]) -> Result<()> {
+    for example in examples {
+        prepare::<S>(example.input)
+            .wrap_err_with(|| format!("failed to parse the example {}", example.name))?;
+    }
+    Ok(())
+}
+
+/// Parse and prepare the local input, if there is one
+pub fn check_input<S: Solution>() -> Result<()> {
+    if let Some(input) = input::local(S::YEAR, S::DAY)? {
+        prepare::<S>(input).wrap_err("failed to parse the input")?;
+    }
+    Ok(())
+}
+
+/// Check the answers to `part` of all the examples that have one
+pub fn check_part<S: Solution>(examples: &[Example], part: Part) -> Result<()> {
+    let mut wrong = vec![];
+    for example in examples {
+        let Some(expected) = example.expected(part) else {
+            continue;
+        };
+
+        let report = run_part::<S>(example.input, Some(part))
+            .wrap_err_with(|| format!("failed to solve the example {}", example.name))?;
+        let answer = match part {
+            Part::One => report.part1,
+            Part::Two => report.part2,
+        }
+        .expect("the part was run");
+
+        if answer != expected {
+            wrong.push(format!(
+                "{}: expected {expected:?}, got {answer:?}",
+                example.name
+            ));
+        }
+    }
+
+    if !wrong.is_empty() {
+        bail!("wrong answers for the examples\n{}", wrong.join("\n"));
+    }
+
+    Ok(())
+}
+
+/// Generate the standard tests of a day from its examples
+///
+/// Each example is a file next to the crate's `lib.rs` followed by the expected
+/// answers, which can be left out for parts that aren't solved yet:
+///
+/// ```ignore
+/// aoc_runner::examples! {
+///     Day14,
+///     "test_input" => { part1: 24, part2: 93 },
+/// }
+/// ```
+///
+/// See the [module documentation](crate::examples) for the generated tests.
+#[macro_export]
+macro_rules! examples {
+    (
+        $solution:ident,
+        $($file:literal => { $($part:ident: $answer:expr),* $(,)? }),+ $(,)?
+    ) => {
+        #[cfg(test)]
+        mod examples {
+            use $crate::{
+                examples::{check_input, check_parse, check_part, Example},
+                Part,
+            };
+
+            type Day = super::$solution;
+
+            fn examples() -> Vec<Example> {
+                vec![$(
+                    Example::new($file, include_str!($file))$(.$part($answer))*
+                ),+]
+            }
+
+            #[test]
+            fn parse() -> ::color_eyre::Result<()> {
+                check_parse::<Day>(&examples())
+            }
+
+            #[test]
+            fn parse_input() -> ::color_eyre::Result<()> {
+                check_input::<Day>()
+            }
+
+            #[test]
+            fn part1() -> ::color_eyre::Result<()> {
+                check_part::<Day>(&examples(), Part::One)
+            }
+
+            #[test]
+            fn part2() -> ::color_eyre::Result<()> {
+                check_part::<Day>(&examples(), Part::Two)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        examples::{check_parse, check_part, Example},
+        Part, Solution,
+    };
+    use color_eyre::Result;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    struct Double;
+
+    impl Solution for Double {
+        const YEAR: u32 = 2022;
+        const DAY: u32 = 1;
+
+        type Parsed = u32;
+        type Prepared = u32;
+        type Answer1 = u32;
+        type Answer2 = u32;
+
+        fn parse(input: &'static str) -> Result<Self::Parsed> {
+            Ok(input.trim_end().parse()?)
+        }
+
+        fn prepare(n: Self::Parsed) -> Result<Self::Prepared> {
+            Ok(n)
+        }
+
+        fn part1(n: &Self::Prepared) -> Result<Self::Answer1> {
+            Ok(n * 2)
+        }
+
+        fn part2(n: &Self::Prepared) -> Result<Self::Answer2> {
+            Ok(n * n)
+        }
+    }
+
+    #[test]
+    fn expected_answers() {
+        let example = Example::new("four", "4\n").part2(16);
+
+        assert_eq!(example.expected(Part::One), None);
+        assert_eq!(example.expected(Part::Two), Some("16"));
+    }
+
+    #[test]
+    fn parse_examples() {
+        assert!(check_parse::<Double>(&[Example::new("four", "4\n")]).is_ok());
+
+        let err = check_parse::<Double>(&[Example::new("four", "4\n"), Example::new("bad", "x")])
+            .unwrap_err();
+        assert_eq!(err.to_string(), "failed to parse the example bad");
+    }
+
+    #[test]
+    fn check_answers() -> Result<()> {
+        let examples = [
+            Example::new("four", "4\n").part1(8).part2(16),
+            Example::new("five", "5\n").part2(25),
+        ];
+
+        check_part::<Double>(&examples, Part::One)?;
+        check_part::<Double>(&examples, Part::Two)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn wrong_answers() {
+        let examples = [
+            Example::new("four", "4\n").part1(8),
+            Example::new("five", "5\n").part1(11),
+            Example::new("six", "6\n").part1(13),
+        ];
+
+        let err = check_part::<Double>(&examples, Part::One).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "wrong answers for the examples
+five: expected \"11\", got \"10\"
+six: expected \"13\", got \"12\""
+        );
+    }
+}
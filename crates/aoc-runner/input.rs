@@ -0,0 +1,134 @@
+//! Find and read the puzzle input at runtime
+//!
+//! The input is looked up in this order:
+//!
+//! 1. An explicit [`Source`], either a path or `-` for stdin
+//! 2. `$AOC_INPUT_DIR/YYYY/NN`, if the `AOC_INPUT_DIR` environment variable is set
+//! 3. `YYYY/day/NN/input` inside the workspace
+use std::{
+    ffi::OsStr,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use color_eyre::{eyre::WrapErr, Help, Result};
+
+/// Environment variable pointing to a directory with one input file per year and day
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl From<&OsStr> for Source {
+    fn from(arg: &OsStr) -> Self {
+        if arg == "-" {
+            Self::Stdin
+        } else {
+            Self::File(arg.into())
+        }
+    }
+}
+
+/// The root of the workspace, with one directory per year
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .and_then(Path::parent)
+        .expect("aoc-runner is in the crates directory of the workspace")
+}
+
+/// The directory of the crate solving `day` of `year`
+pub fn day_dir(year: u32, day: u32) -> PathBuf {
+    workspace_root().join(format!("{year}/day/{day:02}"))
+}
+
+/// The path the input for `day` of `year` is read from when no [`Source`] is given
+pub fn default_path(year: u32, day: u32) -> PathBuf {
+    match std::env::var_os(INPUT_DIR_VAR) {
+        Some(dir) => Path::new(&dir).join(format!("{year}/{day:02}")),
+        None => day_dir(year, day).join("input"),
+    }
+}
+
+/// Read the input for `day` of `year` from `source`, or from its [`default_path`]
+///
+/// The input is leaked so solutions can borrow from it for the rest of the program.
+pub fn load(year: u32, day: u32, source: Option<&Source>) -> Result<&'static str> {
+    let input = match source {
+        Some(Source::Stdin) => {
+            let mut input = String::new();
+            std::io::stdin()
+                .read_to_string(&mut input)
+                .wrap_err("couldn't read input from stdin")?;
+            input
+        }
+        Some(Source::File(path)) => read(path)?,
+        None => read(&default_path(year, day)).with_suggestion(|| {
+            format!(
+                "download the input for day {day} of {year} (`aoc fetch {year} {day}`) \
+                 or point {INPUT_DIR_VAR} to a directory with it"
+            )
+        })?,
+    };
+
+    Ok(Box::leak(input.into_boxed_str()))
+}
+
+/// Read the input from its [`default_path`], if it exists
+///
+/// Used by tests that check the real input, which isn't checked into the repo.
+pub fn local(year: u32, day: u32) -> Result<Option<&'static str>> {
+    if default_path(year, day).exists() {
+        load(year, day, None).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+fn read(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path)
+        .wrap_err_with(|| format!("couldn't read input from {}", path.display()))
+}
+
+#[cfg(test)]
+mod test {
+    use std::ffi::OsStr;
+
+    use crate::input::{load, Source};
+    use color_eyre::Result;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn source_from_arg() {
+        assert_eq!(Source::from(OsStr::new("-")), Source::Stdin);
+        assert_eq!(
+            Source::from(OsStr::new("day/01/input")),
+            Source::File("day/01/input".into())
+        );
+    }
+
+    #[test]
+    fn load_file() -> Result<()> {
+        let path = std::env::temp_dir().join("aoc-runner-load-file");
+        std::fs::write(&path, "1\n2\n")?;
+
+        assert_eq!(load(2022, 1, Some(&Source::File(path)))?, "1\n2\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_file() {
+        let path = std::env::temp_dir().join("aoc-runner-missing-file");
+        let err = load(2022, 1, Some(&Source::File(path.clone()))).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!("couldn't read input from {}", path.display())
+        );
+    }
+}
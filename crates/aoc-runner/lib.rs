@@ -0,0 +1,270 @@
+use std::{
+    fmt::{Display, Write},
+    time::{Duration, Instant},
+};
+
+use color_eyre::Result;
+
+pub mod answers;
+pub mod bench;
+pub mod examples;
+pub mod input;
+pub mod parse;
+
+/// A solution to an Advent of Code problem
+///
+/// The runner calls [`Solution::parse`], then [`Solution::prepare`] and finally both
+/// [`Solution::part1`] and [`Solution::part2`] on the prepared data, timing each step.
+pub trait Solution {
+    /// Year of the problem this solves
+    const YEAR: u32;
+    /// Day of the problem this solves
+    const DAY: u32;
+
+    /// Output of [`Solution::parse`]
+    type Parsed;
+    /// Data structures shared by Problem 1 and Problem 2
+    type Prepared;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &'static str) -> Result<Self::Parsed>;
+
+    fn prepare(data: Self::Parsed) -> Result<Self::Prepared>;
+
+    fn part1(data: &Self::Prepared) -> Result<Self::Answer1>;
+
+    fn part2(data: &Self::Prepared) -> Result<Self::Answer2>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub prepare: Duration,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+    pub total: Duration,
+}
+
+/// The answers and timings of running a [`Solution`]
+///
+/// Parts that were not run have no answer nor timing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub timings: Timings,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// A type erased [`run_part`], used to run solutions picked at runtime
+pub type Runner = fn(&'static str, Option<Part>) -> Result<Report>;
+
+fn timed<T>(f: impl FnOnce() -> Result<T>) -> Result<(T, Duration)> {
+    let now = Instant::now();
+    let res = f()?;
+    Ok((res, now.elapsed()))
+}
+
+/// Run the [`Solution`] on `input` without printing anything
+pub fn run<S: Solution>(input: &'static str) -> Result<Report> {
+    run_part::<S>(input, None)
+}
+
+/// Run only one [`Part`] of the [`Solution`], or both if `part` is [`None`]
+pub fn run_part<S: Solution>(input: &'static str, part: Option<Part>) -> Result<Report> {
+    let now = Instant::now();
+
+    let (data, parse) = timed(|| S::parse(input))?;
+    let (data, prepare) = timed(|| S::prepare(data))?;
+
+    let part1 = if part != Some(Part::Two) {
+        let (answer, time) = timed(|| S::part1(&data))?;
+        Some((answer.to_string(), time))
+    } else {
+        None
+    };
+
+    let part2 = if part != Some(Part::One) {
+        let (answer, time) = timed(|| S::part2(&data))?;
+        Some((answer.to_string(), time))
+    } else {
+        None
+    };
+
+    Ok(Report {
+        timings: Timings {
+            parse,
+            prepare,
+            part1: part1.as_ref().map(|(_, time)| *time),
+            part2: part2.as_ref().map(|(_, time)| *time),
+            total: now.elapsed(),
+        },
+        part1: part1.map(|(answer, _)| answer),
+        part2: part2.map(|(answer, _)| answer),
+    })
+}
+
+/// Entry point shared by all the days
+///
+/// The first argument is an optional path to the input, `-` reads it from stdin.
+pub fn main<S: Solution>() -> Result<()> {
+    color_eyre::install()?;
+
+    let source = std::env::args_os()
+        .nth(1)
+        .map(|arg| input::Source::from(arg.as_os_str()));
+    let input = input::load(S::YEAR, S::DAY, source.as_ref())?;
+
+    let report = run::<S>(input)?;
+    print!("{report}");
+
+    Ok(())
+}
+
+impl Report {
+    /// Write only the answers, without the timings
+    pub fn write_answers(&self, f: &mut impl Write) -> std::fmt::Result {
+        if let Some(answer) = &self.part1 {
+            write_answer(f, "Problem 1:", answer)?;
+        }
+        if let Some(answer) = &self.part2 {
+            write_answer(f, "Problem 2:", answer)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_answer(f: &mut impl Write, label: &str, answer: &str) -> std::fmt::Result {
+    if answer.contains('\n') {
+        writeln!(f, "{label}")?;
+        writeln!(f, "{}", answer.trim_end())
+    } else {
+        writeln!(f, "{label:<17}{answer:>16}")
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Timings {
+            parse,
+            prepare,
+            part1,
+            part2,
+            total,
+        } = self.timings;
+
+        writeln!(f, "Parsing took:    {parse:>16?}")?;
+        writeln!(f, "Processing took: {prepare:>16?}")?;
+        if let Some(part1) = part1 {
+            writeln!(f, "Problem 1 took:  {part1:>16?}")?;
+        }
+        if let Some(part2) = part2 {
+            writeln!(f, "Problem 2 took:  {part2:>16?}")?;
+        }
+        writeln!(f, "Total runtime:   {total:>16?}")?;
+        writeln!(f, "----------------O----------------")?;
+        self.write_answers(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::{run, run_part, Part, Report, Solution, Timings};
+    use color_eyre::Result;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u32 = 2022;
+        const DAY: u32 = 1;
+
+        type Parsed = Vec<u32>;
+        type Prepared = Vec<u32>;
+        type Answer1 = u32;
+        type Answer2 = String;
+
+        fn parse(input: &'static str) -> Result<Self::Parsed> {
+            Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+        }
+
+        fn prepare(mut data: Self::Parsed) -> Result<Self::Prepared> {
+            data.sort_unstable();
+            Ok(data)
+        }
+
+        fn part1(data: &Self::Prepared) -> Result<Self::Answer1> {
+            Ok(data.iter().sum())
+        }
+
+        fn part2(data: &Self::Prepared) -> Result<Self::Answer2> {
+            Ok(data.iter().map(|n| format!("{n}\n")).collect())
+        }
+    }
+
+    #[test]
+    fn run_solution() -> Result<()> {
+        let report = run::<Sum>("3\n1\n2\n")?;
+
+        assert_eq!(report.part1.as_deref(), Some("6"));
+        assert_eq!(report.part2.as_deref(), Some("1\n2\n3\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn run_single_part() -> Result<()> {
+        let report = run_part::<Sum>("3\n1\n2\n", Some(Part::Two))?;
+
+        assert_eq!(report.part1, None);
+        assert_eq!(report.timings.part1, None);
+        assert_eq!(report.part2.as_deref(), Some("1\n2\n3\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_error() {
+        assert!(run::<Sum>("3\na\n").is_err());
+    }
+
+    #[test]
+    fn display_report() {
+        let report = Report {
+            timings: Timings {
+                part1: Some(Duration::ZERO),
+                part2: Some(Duration::ZERO),
+                ..Default::default()
+            },
+            part1: Some("6".to_string()),
+            part2: Some("1\n2\n".to_string()),
+        };
+        let zero = format!("{:>16?}", Duration::ZERO);
+
+        assert_eq!(
+            report.to_string(),
+            format!(
+                "Parsing took:    {zero}
+Processing took: {zero}
+Problem 1 took:  {zero}
+Problem 2 took:  {zero}
+Total runtime:   {zero}
+----------------O----------------
+Problem 1:                      6
+Problem 2:
+1
+2
+"
+            )
+        );
+    }
+}
@@ -0,0 +1,285 @@
+//! Shared front-end for the nom parsers of every day
+//!
+//! Parsers use this module's [`IResult`], which collects an [`ErrorTree`] instead of nom's
+//! default error. [`complete`] runs a parser on the whole input and turns a failure into a
+//! [`BadInput`], which renders as a miette report pointing at the offending line and column.
+use std::fmt::{Debug, Display};
+
+use miette::{Diagnostic, GraphicalReportHandler, LabeledSpan, SourceCode, SourceSpan};
+use nom::{error::ErrorKind, Offset, Parser};
+use nom_supreme::{
+    error::{BaseErrorKind, ErrorTree, GenericErrorTree, StackContext},
+    ParserExt,
+};
+
+/// Drop-in replacement for [`nom::IResult`] collecting an [`ErrorTree`]
+pub type IResult<I, O> = nom::IResult<I, O, ErrorTree<I>>;
+
+/// Run `parser` on the whole `input`
+///
+/// Fails if the parser fails or if it doesn't consume all of the input.
+pub fn complete<O>(
+    parser: impl Parser<&'static str, O, ErrorTree<&'static str>>,
+    input: &'static str,
+) -> Result<O, BadInput> {
+    match parser.all_consuming().parse(input) {
+        Ok((_, output)) => Ok(output),
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => Err(BadInput::new(input, &err)),
+        Err(nom::Err::Incomplete(_)) => Err(BadInput::incomplete(input)),
+    }
+}
+
+/// The input couldn't be parsed
+///
+/// [`Display`] renders the whole miette report, so the diagnostic survives being wrapped in an
+/// [`eyre::Report`](color_eyre::Report). Use [`BadInput::diagnostic`] to inspect it instead.
+#[derive(Debug)]
+pub struct BadInput(Box<ParseDiagnostic>);
+
+impl BadInput {
+    fn new(input: &'static str, err: &ErrorTree<&'static str>) -> Self {
+        let mut failures = vec![];
+        collect_failures(input, err, &mut vec![], &mut failures);
+
+        // Report the alternatives that got furthest into the input
+        let offset = failures.iter().map(|f| f.offset).max().unwrap_or_default();
+        let mut expected = vec![];
+        let mut contexts: Vec<(usize, String)> = vec![];
+        for failure in failures.into_iter().filter(|f| f.offset == offset) {
+            if !expected.contains(&failure.expected) {
+                expected.push(failure.expected);
+            }
+            for context in failure.contexts {
+                if !contexts.contains(&context) {
+                    contexts.push(context);
+                }
+            }
+        }
+
+        Self::with(input, offset, expected, contexts)
+    }
+
+    fn incomplete(input: &'static str) -> Self {
+        Self::with(input, input.len(), vec!["more input".to_string()], vec![])
+    }
+
+    fn with(
+        src: &'static str,
+        offset: usize,
+        expected: Vec<String>,
+        contexts: Vec<(usize, String)>,
+    ) -> Self {
+        let before = &src[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before[before.rfind('\n').map_or(0, |ix| ix + 1)..]
+            .chars()
+            .count()
+            + 1;
+
+        Self(Box::new(ParseDiagnostic {
+            src,
+            offset,
+            line,
+            column,
+            expected,
+            contexts,
+        }))
+    }
+
+    pub fn diagnostic(&self) -> &ParseDiagnostic {
+        &self.0
+    }
+}
+
+impl Display for BadInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut report = String::new();
+        GraphicalReportHandler::new().render_report(&mut report, self.diagnostic())?;
+        f.write_str(report.trim_end())
+    }
+}
+
+impl std::error::Error for BadInput {}
+
+/// Where and why the parser failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDiagnostic {
+    src: &'static str,
+    /// Byte offset of the failure
+    pub offset: usize,
+    /// 1-based line of the failure
+    pub line: usize,
+    /// 1-based column (in chars) of the failure
+    pub column: usize,
+    /// What each alternative expected to find at the failure
+    pub expected: Vec<String>,
+    /// The contexts the parser was in when it failed, innermost first
+    pub contexts: Vec<(usize, String)>,
+}
+
+impl Display for ParseDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "bad input at line {}, column {}", self.line, self.column)
+    }
+}
+
+impl std::error::Error for ParseDiagnostic {}
+
+impl Diagnostic for ParseDiagnostic {
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&self.src)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let expected = match self.expected.as_slice() {
+            [] => "unexpected input".to_string(),
+            [one] => format!("expected {one}"),
+            many => format!("expected one of {}", many.join(", ")),
+        };
+        let primary = LabeledSpan::new_with_span(Some(expected), self.span(self.offset));
+        let contexts = self.contexts.iter().map(|(offset, context)| {
+            LabeledSpan::new_with_span(Some(format!("in {context}")), self.span(*offset))
+        });
+
+        Some(Box::new(std::iter::once(primary).chain(contexts)))
+    }
+}
+
+impl ParseDiagnostic {
+    /// Span of the char at `offset`, empty at the end of the input
+    fn span(&self, offset: usize) -> SourceSpan {
+        let len = self.src[offset..].chars().next().map_or(0, char::len_utf8);
+        (offset, len).into()
+    }
+}
+
+struct Failure {
+    offset: usize,
+    expected: String,
+    contexts: Vec<(usize, String)>,
+}
+
+/// Flatten the tree into the failures at its leaves, with the contexts leading to them
+fn collect_failures(
+    input: &str,
+    err: &ErrorTree<&str>,
+    contexts: &mut Vec<(usize, String)>,
+    failures: &mut Vec<Failure>,
+) {
+    match err {
+        GenericErrorTree::Base { location, kind } => failures.push(Failure {
+            offset: input.offset(location),
+            expected: describe(kind),
+            contexts: contexts.iter().rev().cloned().collect(),
+        }),
+        GenericErrorTree::Stack {
+            base,
+            contexts: stack,
+        } => {
+            let depth = contexts.len();
+            // The stack is ordered innermost first
+            contexts.extend(stack.iter().rev().map(|(location, context)| {
+                let context = match context {
+                    StackContext::Kind(kind) => kind.description().to_string(),
+                    StackContext::Context(context) => context.to_string(),
+                };
+                (input.offset(location), context)
+            }));
+            collect_failures(input, base, contexts, failures);
+            contexts.truncate(depth);
+        }
+        GenericErrorTree::Alt(alternatives) => {
+            for alternative in alternatives {
+                collect_failures(input, alternative, contexts, failures);
+            }
+        }
+    }
+}
+
+fn describe<T: Debug, E: Display>(kind: &BaseErrorKind<T, E>) -> String {
+    match kind {
+        BaseErrorKind::Expected(expectation) => expectation.to_string(),
+        BaseErrorKind::Kind(ErrorKind::Eof) => "end of input".to_string(),
+        BaseErrorKind::Kind(kind) => format!("valid {}", kind.description()),
+        BaseErrorKind::External(err) => err.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use nom::{
+        branch::alt,
+        character::complete::{char, u32},
+        combinator::value,
+        multi::separated_list1,
+        sequence::{delimited, separated_pair},
+        Parser,
+    };
+    use nom_supreme::ParserExt;
+
+    use crate::parse::{complete, IResult};
+    use color_eyre::Result;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    fn list(input: &str) -> IResult<&str, Vec<u32>> {
+        delimited(
+            char('['),
+            separated_list1(char(','), alt((u32, value(0, char('x'))))),
+            char(']'),
+        )
+        .context("list")
+        .parse(input)
+    }
+
+    fn lines(input: &str) -> IResult<&str, (Vec<u32>, Vec<u32>)> {
+        separated_pair(list, char('\n'), list)(input)
+    }
+
+    #[test]
+    fn parse_complete() -> Result<()> {
+        assert_eq!(complete(lines, "[1,2]\n[x]")?, (vec![1, 2], vec![0]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn line_and_column() {
+        let err = complete(lines, "[1,2]\n[;]").unwrap_err();
+        let diagnostic = err.diagnostic();
+
+        assert_eq!((diagnostic.line, diagnostic.column), (2, 2));
+        assert_eq!(diagnostic.offset, 7);
+    }
+
+    #[test]
+    fn expected_alternatives() {
+        let err = complete(lines, "[1,2]\n[;]").unwrap_err();
+        let diagnostic = err.diagnostic();
+
+        assert_eq!(diagnostic.expected, ["an ascii digit", "'x'"]);
+        assert_eq!(diagnostic.contexts, [(6, "list".to_string())]);
+    }
+
+    #[test]
+    fn trailing_input() {
+        let err = complete(lines, "[1]\n[2]\nnope").unwrap_err();
+        let diagnostic = err.diagnostic();
+
+        assert_eq!((diagnostic.line, diagnostic.column), (2, 4));
+        assert_eq!(diagnostic.expected, ["eof"]);
+    }
+
+    #[test]
+    fn rendered_report() {
+        let err = complete(lines, "[1,2]\n[;]").unwrap_err();
+        let report = err.to_string();
+
+        assert!(report.contains("bad input at line 2, column 2"), "{report}");
+        assert!(
+            report.contains("expected one of an ascii digit, 'x'"),
+            "{report}"
+        );
+        assert!(report.contains("in list"), "{report}");
+    }
+}
@@ -0,0 +1,521 @@
+//! Dense 2D grids indexed by signed coordinates
+//!
+//! A [`Grid`] covers a rectangle of positions that doesn't need to start at `(0, 0)`,
+//! so puzzles with cells around some arbitrary point (like sand falling from `(500, 0)`)
+//! can index it directly. The bounds grow on demand with [`Grid::grow_to`].
+use std::{
+    fmt::{Debug, Display},
+    iter,
+    ops::{Index, IndexMut},
+};
+
+use nom::{
+    error::{ErrorKind, FromExternalError, ParseError},
+    IResult, Parser,
+};
+
+use crate::vector::Vec2;
+
+/// A position in a [`Grid`], `y` grows downwards
+pub type Pos = Vec2<isize>;
+
+/// A rectangle of cells stored row by row
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    min: Pos,
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` by `height` grid with its top left corner at `(0, 0)`
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self::from_vec(width, vec![fill; width * height])
+    }
+
+    /// A grid covering all the positions from `min` to `max`, both included
+    pub fn with_bounds(min: Pos, max: Pos, fill: T) -> Self {
+        assert!(
+            min.x <= max.x && min.y <= max.y,
+            "empty bounds {min:?}..={max:?}"
+        );
+        let width = (max.x - min.x) as usize + 1;
+        let height = (max.y - min.y) as usize + 1;
+
+        Self {
+            min,
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Grow the bounds until they include `pos`, filling the new cells with `fill`
+    pub fn grow_to(&mut self, pos: Pos, fill: T) {
+        if self.contains(pos) {
+            return;
+        }
+        if self.cells.is_empty() {
+            *self = Self::with_bounds(pos, pos, fill);
+            return;
+        }
+
+        let max = self.max();
+        let min = self.min.componentwise_min(pos);
+        let mut grown = Self::with_bounds(min, max.componentwise_max(pos), fill);
+
+        let (dx, dy) = ((self.min.x - min.x) as usize, (self.min.y - min.y) as usize);
+        for (y, row) in self.cells.chunks_exact_mut(self.width).enumerate() {
+            let start = (y + dy) * grown.width + dx;
+            grown.cells[start..start + self.width].swap_with_slice(row);
+        }
+
+        *self = grown;
+    }
+}
+
+impl<T> Grid<T> {
+    /// A grid with its top left corner at `(0, 0)` from its cells, row by row
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        let height = if width == 0 {
+            assert!(cells.is_empty(), "a grid without columns can't have cells");
+            0
+        } else {
+            assert!(
+                cells.len().is_multiple_of(width),
+                "{} cells don't fill rows of {width}",
+                cells.len()
+            );
+            cells.len() / width
+        };
+
+        Self {
+            min: Vec2::new(0, 0),
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parse a grid with one character per cell and one row per line
+    pub fn parse_chars<E>(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, ParseGridError<E>> {
+        let mut cells = vec![];
+        let mut width = None;
+        for (ix, row) in input.lines().enumerate() {
+            let start = cells.len();
+            for (column, c) in row.chars().enumerate() {
+                let value = cell(c).map_err(|source| ParseGridError::Cell {
+                    line: ix + 1,
+                    column: column + 1,
+                    source,
+                })?;
+                cells.push(value);
+            }
+
+            let found = cells.len() - start;
+            match width {
+                None => width = Some(found),
+                Some(expected) if expected != found => {
+                    return Err(ParseGridError::Ragged {
+                        line: ix + 1,
+                        expected,
+                        found,
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+
+        Ok(Self::from_vec(width.unwrap_or(0), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The top left corner
+    pub fn min(&self) -> Pos {
+        self.min
+    }
+
+    /// The bottom right corner, meaningless if the grid is empty
+    pub fn max(&self) -> Pos {
+        self.min + Vec2::new(self.width as isize - 1, self.height as isize - 1)
+    }
+
+    /// Move the grid so its top left corner is at `min`
+    pub fn with_min(mut self, min: Pos) -> Self {
+        self.min = min;
+        self
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.index_of(pos).is_some()
+    }
+
+    fn index_of(&self, pos: Pos) -> Option<usize> {
+        let x = usize::try_from(pos.x - self.min.x).ok()?;
+        let y = usize::try_from(pos.y - self.min.y).ok()?;
+        (x < self.width && y < self.height).then_some(x + y * self.width)
+    }
+
+    fn pos_of(&self, ix: usize) -> Pos {
+        self.min + Vec2::new((ix % self.width) as isize, (ix / self.width) as isize)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|ix| &self.cells[ix])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(|ix| &mut self.cells[ix])
+    }
+
+    /// All the cells, row by row
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// All the positions and their cells, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(ix, cell)| (self.pos_of(ix), cell))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Pos, &mut T)> {
+        let (min, width) = (self.min, self.width);
+        self.cells.iter_mut().enumerate().map(move |(ix, cell)| {
+            let pos = min + Vec2::new((ix % width) as isize, (ix / width) as isize);
+            (pos, cell)
+        })
+    }
+
+    /// All the positions, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        (0..self.cells.len()).map(|ix| self.pos_of(ix))
+    }
+
+    /// The first position, row by row, whose cell matches `pred`
+    pub fn position(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, cell)| pred(cell)).map(|(pos, _)| pos)
+    }
+
+    /// The orthogonal neighbors of `pos` inside the grid
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.inside(pos.neighbors4())
+    }
+
+    /// The orthogonal and diagonal neighbors of `pos` inside the grid
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.inside(pos.neighbors8())
+    }
+
+    fn inside<const N: usize>(&self, positions: [Pos; N]) -> impl Iterator<Item = Pos> + '_ {
+        positions.into_iter().filter(|&pos| self.contains(pos))
+    }
+
+    /// The cells from `from` (excluded) in steps of `step` until the edge of the grid
+    ///
+    /// Never ends if `step` is `(0, 0)`.
+    pub fn ray(&self, from: Pos, step: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        iter::successors(Some(from + step), move |&pos| Some(pos + step))
+            .map_while(|pos| self.get(pos).map(|cell| (pos, cell)))
+    }
+
+    /// The cells of the row at `y`
+    pub fn row(&self, y: isize) -> Option<&[T]> {
+        let start = self.index_of(Vec2::new(self.min.x, y))?;
+        Some(&self.cells[start..start + self.width])
+    }
+
+    pub fn row_mut(&mut self, y: isize) -> Option<&mut [T]> {
+        let start = self.index_of(Vec2::new(self.min.x, y))?;
+        Some(&mut self.cells[start..start + self.width])
+    }
+
+    /// The rows from top to bottom
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    /// The cells of the column at `x`, from top to bottom
+    pub fn column(&self, x: isize) -> Option<impl Iterator<Item = &T>> {
+        let start = self.index_of(Vec2::new(x, self.min.y))?;
+        Some(self.cells[start..].iter().step_by(self.width))
+    }
+
+    /// A grid with the same bounds and the cells transformed by `f`
+    pub fn map<U>(&self, mut f: impl FnMut(Pos, &T) -> U) -> Grid<U> {
+        Grid {
+            min: self.min,
+            width: self.width,
+            height: self.height,
+            cells: self.iter().map(|(pos, cell)| f(pos, cell)).collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!("{pos:?} is outside of {:?}..={:?}", self.min, self.max()),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        let (min, max) = (self.min, self.max());
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("{pos:?} is outside of {min:?}..={max:?}"),
+        }
+    }
+}
+
+/// Renders one row per line, each cell with its own [`Display`]
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Debug> Debug for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Grid")
+            .field("min", &self.min)
+            .field("max", &self.max())
+            .field("rows", &self.rows().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum ParseGridError<E> {
+    #[error("line {line} has {found} cells instead of {expected}")]
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("invalid cell at line {line}, column {column}")]
+    Cell {
+        line: usize,
+        column: usize,
+        #[source]
+        source: E,
+    },
+}
+
+/// A row with a different number of cells than the first one, reported by [`rows`]
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+#[error("a row of {expected} cells, not {found}")]
+pub struct RaggedRow {
+    pub expected: usize,
+    pub found: usize,
+}
+
+/// Parse a grid with one `cell` per column and one row per line
+///
+/// Every row ends with a line ending and has as many cells as the first one.
+pub fn rows<'a, T, E>(
+    mut cell: impl Parser<&'a str, T, E>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Grid<T>, E>
+where
+    E: ParseError<&'a str> + FromExternalError<&'a str, RaggedRow>,
+{
+    move |mut input| {
+        let mut cells = vec![];
+        let mut width = None;
+
+        loop {
+            let row = input;
+            let mut found = 0;
+            let err = loop {
+                match cell.parse(input) {
+                    Ok((rest, value)) => {
+                        cells.push(value);
+                        found += 1;
+                        input = rest;
+                    }
+                    Err(nom::Err::Error(err)) => break err,
+                    Err(err) => return Err(err),
+                }
+            };
+
+            if found == 0 {
+                return match width {
+                    // Not even a single row
+                    None => Err(nom::Err::Error(E::append(row, ErrorKind::Many1, err))),
+                    Some(width) => Ok((row, Grid::from_vec(width, cells))),
+                };
+            }
+
+            input = match nom::character::complete::line_ending::<_, E>(input) {
+                Ok((rest, _)) => rest,
+                Err(nom::Err::Error(line_err)) => return Err(nom::Err::Error(err.or(line_err))),
+                Err(err) => return Err(err),
+            };
+
+            match width {
+                None => width = Some(found),
+                Some(expected) if expected != found => {
+                    return Err(nom::Err::Failure(E::from_external_error(
+                        row,
+                        ErrorKind::Verify,
+                        RaggedRow { expected, found },
+                    )))
+                }
+                Some(_) => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        grid::{rows, Grid, ParseGridError},
+        vector::Vec2,
+    };
+    use aoc_runner::parse::complete;
+    use color_eyre::Result;
+    use nom::character::complete::one_of;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[derive(Debug, thiserror::Error, PartialEq, Eq)]
+    #[error("{0:?} is not a digit")]
+    struct NotADigit(char);
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseGridError<NotADigit>> {
+        Grid::parse_chars(input, |c| c.to_digit(10).ok_or(NotADigit(c)))
+    }
+
+    #[test]
+    fn parse_chars() -> Result<()> {
+        let grid = digits("123\n456\n")?;
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Vec2::new(2, 1)], 6);
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        assert_eq!(
+            digits("12\n4x\n"),
+            Err(ParseGridError::Cell {
+                line: 2,
+                column: 2,
+                source: NotADigit('x')
+            })
+        );
+        assert_eq!(
+            digits("12\n456\n"),
+            Err(ParseGridError::Ragged {
+                line: 2,
+                expected: 2,
+                found: 3
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_rows() -> Result<()> {
+        let grid = complete(rows(one_of(".#")), ".#.\n##.\n")?;
+
+        assert_eq!(grid.to_string(), ".#.\n##.\n");
+
+        let err = complete(rows(one_of(".#")), ".#.\n##\n").unwrap_err();
+        let diagnostic = err.diagnostic();
+        assert_eq!((diagnostic.line, diagnostic.column), (2, 1));
+        assert_eq!(diagnostic.expected, ["a row of 3 cells, not 2"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn offset_bounds() {
+        let mut grid = Grid::with_bounds(Vec2::new(-2, 3), Vec2::new(0, 4), 0);
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert!(grid.contains(Vec2::new(-2, 3)));
+        assert!(!grid.contains(Vec2::new(1, 3)));
+        assert!(!grid.contains(Vec2::new(0, 2)));
+
+        grid[Vec2::new(-1, 4)] = 1;
+        assert_eq!(grid.row(4), Some(&[0, 1, 0][..]));
+        assert_eq!(grid.column(-1).unwrap().collect::<Vec<_>>(), [&0, &1]);
+        assert_eq!(grid.position(|&cell| cell == 1), Some(Vec2::new(-1, 4)));
+    }
+
+    #[test]
+    fn grow() {
+        let mut grid = Grid::with_bounds(Vec2::new(0, 0), Vec2::new(1, 0), 1);
+
+        grid.grow_to(Vec2::new(-1, 2), 0);
+        assert_eq!(
+            (grid.min(), grid.max()),
+            (Vec2::new(-1, 0), Vec2::new(1, 2))
+        );
+        assert_eq!(grid.to_string(), "011\n000\n000\n");
+
+        grid.grow_to(Vec2::new(0, 1), 2);
+        assert_eq!(grid.to_string(), "011\n000\n000\n");
+
+        let mut empty = Grid::new(0, 0, 0);
+        empty.grow_to(Vec2::new(5, 5), 1);
+        assert_eq!(
+            (empty.min(), empty.max()),
+            (Vec2::new(5, 5), Vec2::new(5, 5))
+        );
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = Grid::new(3, 3, ());
+
+        assert_eq!(
+            grid.neighbors4(Vec2::new(0, 0)).collect::<Vec<_>>(),
+            [Vec2::new(1, 0), Vec2::new(0, 1)]
+        );
+        assert_eq!(grid.neighbors4(Vec2::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8(Vec2::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbors8(Vec2::new(2, 2)).count(), 3);
+    }
+
+    #[test]
+    fn ray() -> Result<()> {
+        let grid = digits("123\n456\n789\n")?;
+
+        let cells = |step| {
+            grid.ray(Vec2::new(0, 0), step)
+                .map(|(_, &c)| c)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(cells(Vec2::new(1, 0)), [2, 3]);
+        assert_eq!(cells(Vec2::new(1, 1)), [5, 9]);
+        assert_eq!(cells(Vec2::new(-1, 0)), [0; 0]);
+
+        Ok(())
+    }
+}
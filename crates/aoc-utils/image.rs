@@ -0,0 +1,322 @@
+//! RGB images to visualize grids, saved as PPM or PNG, or as the frames of an animated GIF
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::grid::{Grid, Pos};
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+
+#[derive(Debug, thiserror::Error)]
+pub enum ImageError {
+    #[error("couldn't write the image")]
+    Io(#[from] io::Error),
+    #[error("couldn't encode the image as PNG")]
+    Png(#[from] png::EncodingError),
+    #[error("unknown image format for {0}, expected a .ppm or .png file")]
+    UnknownFormat(PathBuf),
+    #[error("couldn't encode the animation as GIF")]
+    Gif(#[from] gif::EncodingError),
+    #[error("a GIF can't be {0}x{1} pixels, the most is 65535 on each side")]
+    TooLarge(usize, usize),
+    #[error("the frame is {found:?} pixels, the animation is {expected:?}")]
+    FrameSize {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, fill: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![fill; width * height],
+        }
+    }
+
+    /// One pixel per cell of `grid`, colored by `color`
+    pub fn from_grid<T>(grid: &Grid<T>, mut color: impl FnMut(Pos, &T) -> Rgb) -> Self {
+        Self {
+            width: grid.width(),
+            height: grid.height(),
+            pixels: grid.iter().map(|(pos, cell)| color(pos, cell)).collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The pixels row by row, from the top left
+    pub fn pixels(&self) -> &[Rgb] {
+        &self.pixels
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<Rgb> {
+        (x < self.width && y < self.height).then(|| self.pixels[y * self.width + x])
+    }
+
+    pub fn pixel_mut(&mut self, x: usize, y: usize) -> Option<&mut Rgb> {
+        (x < self.width && y < self.height).then(|| &mut self.pixels[y * self.width + x])
+    }
+
+    /// Each pixel becomes a `factor` by `factor` square, tiny grids are hard to see
+    pub fn scaled(&self, factor: usize) -> Self {
+        let width = self.width * factor;
+        let pixels = (0..self.height * factor)
+            .flat_map(|y| (0..width).map(move |x| (x / factor, y / factor)))
+            .map(|(x, y)| self.pixels[y * self.width + x])
+            .collect();
+
+        Self {
+            width,
+            height: self.height * factor,
+            pixels,
+        }
+    }
+
+    /// Write the image as a binary PPM (`P6`)
+    pub fn write_ppm(&self, mut writer: impl Write) -> io::Result<()> {
+        write!(writer, "P6\n{} {}\n255\n", self.width, self.height)?;
+        writer.write_all(self.pixels.as_flattened())
+    }
+
+    pub fn write_png(&self, writer: impl Write) -> Result<(), png::EncodingError> {
+        let mut encoder = png::Encoder::new(writer, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(self.pixels.as_flattened())?;
+        writer.finish()
+    }
+
+    /// Save the image in the format matching the extension of `path`
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ImageError> {
+        let path = path.as_ref();
+        let extension = path.extension().and_then(|ext| ext.to_str());
+        if !matches!(extension, Some("ppm" | "png")) {
+            return Err(ImageError::UnknownFormat(path.to_owned()));
+        }
+
+        let mut writer = BufWriter::new(File::create(path)?);
+        if extension == Some("png") {
+            self.write_png(&mut writer)?;
+        } else {
+            self.write_ppm(&mut writer)?;
+        }
+        writer.flush()?;
+
+        Ok(())
+    }
+}
+
+/// The colors of `image` and the index of the color of each pixel, if it has at most 256
+fn palette(image: &Image) -> Option<(Vec<Rgb>, Vec<u8>)> {
+    let mut palette: Vec<Rgb> = vec![];
+    let mut indices = Vec::with_capacity(image.pixels.len());
+    for pixel in &image.pixels {
+        let ix = match palette.iter().position(|color| color == pixel) {
+            Some(ix) => ix,
+            None if palette.len() < 256 => {
+                palette.push(*pixel);
+                palette.len() - 1
+            }
+            None => return None,
+        };
+        indices.push(ix as u8);
+    }
+    Some((palette, indices))
+}
+
+/// Writes images as the frames of an animated GIF, looping forever
+///
+/// The frames are written as they come, so long animations don't have to fit in memory.
+pub struct GifWriter<W: Write> {
+    encoder: gif::Encoder<W>,
+    width: usize,
+    height: usize,
+    /// How long each frame is shown, in hundredths of a second
+    delay: u16,
+}
+
+impl<W: Write> GifWriter<W> {
+    /// An animation of `width` by `height` frames, each shown for `delay`
+    pub fn new(
+        writer: W,
+        width: usize,
+        height: usize,
+        delay: Duration,
+    ) -> Result<Self, ImageError> {
+        let (Ok(w), Ok(h)) = (u16::try_from(width), u16::try_from(height)) else {
+            return Err(ImageError::TooLarge(width, height));
+        };
+        let mut encoder = gif::Encoder::new(writer, w, h, &[])?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+
+        Ok(Self {
+            encoder,
+            width,
+            height,
+            delay: u16::try_from(delay.as_millis() / 10).unwrap_or(u16::MAX),
+        })
+    }
+
+    pub fn write_frame(&mut self, image: &Image) -> Result<(), ImageError> {
+        if (image.width, image.height) != (self.width, self.height) {
+            return Err(ImageError::FrameSize {
+                expected: (self.width, self.height),
+                found: (image.width, image.height),
+            });
+        }
+
+        let (w, h) = (self.width as u16, self.height as u16);
+        let mut frame = match palette(image) {
+            Some((palette, indices)) => {
+                gif::Frame::from_palette_pixels(w, h, indices, palette.as_flattened(), None)
+            }
+            // Too many colors, let the encoder pick the closest ones
+            None => gif::Frame::from_rgb_speed(w, h, image.pixels.as_flattened(), 10),
+        };
+        frame.delay = self.delay;
+        self.encoder.write_frame(&frame)?;
+
+        Ok(())
+    }
+
+    /// End the animation, returning the writer
+    pub fn finish(self) -> Result<W, ImageError> {
+        Ok(self.encoder.into_inner()?)
+    }
+}
+
+/// A color from blue (`0.0`) through green to red (`1.0`), for heat maps
+pub fn heat(t: f64) -> Rgb {
+    let t = t.clamp(0.0, 1.0);
+    let channel = |center: f64| ((1.0 - (t - center).abs() * 2.0).clamp(0.0, 1.0) * 255.0) as u8;
+    [channel(1.0), channel(0.5), channel(0.0)]
+}
+
+/// A shade of gray from black (`0.0`) to white (`1.0`)
+pub fn gray(t: f64) -> Rgb {
+    let level = (t.clamp(0.0, 1.0) * 255.0) as u8;
+    [level; 3]
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::{
+        grid::Grid,
+        image::{heat, GifWriter, Image, ImageError, BLACK, WHITE},
+    };
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn from_grid() {
+        let grid = Grid::from_vec(2, vec![true, false, false, true]);
+        let image = Image::from_grid(&grid, |_, &set| if set { WHITE } else { BLACK });
+
+        assert_eq!((image.width(), image.height()), (2, 2));
+        assert_eq!(image.pixels(), [WHITE, BLACK, BLACK, WHITE]);
+
+        let scaled = image.scaled(2);
+        assert_eq!((scaled.width(), scaled.height()), (4, 4));
+        assert_eq!(scaled.get(1, 1), Some(WHITE));
+        assert_eq!(scaled.get(2, 1), Some(BLACK));
+        assert_eq!(scaled.get(3, 3), Some(WHITE));
+        assert_eq!(scaled.get(4, 0), None);
+    }
+
+    #[test]
+    fn ppm() -> std::io::Result<()> {
+        let mut image = Image::new(2, 1, BLACK);
+        image.pixels[1] = [1, 2, 3];
+
+        let mut out = vec![];
+        image.write_ppm(&mut out)?;
+        assert_eq!(out, b"P6\n2 1\n255\n\0\0\0\x01\x02\x03");
+
+        Ok(())
+    }
+
+    #[test]
+    fn png() -> Result<(), png::EncodingError> {
+        let mut out = vec![];
+        Image::new(3, 2, WHITE).write_png(&mut out)?;
+        assert_eq!(&out[1..4], b"PNG");
+
+        Ok(())
+    }
+
+    #[test]
+    fn gif() -> Result<(), ImageError> {
+        let mut gif = GifWriter::new(vec![], 2, 2, Duration::from_millis(50))?;
+        gif.write_frame(&Image::new(2, 2, WHITE))?;
+
+        let mut checkered = Image::new(2, 2, BLACK);
+        checkered.pixels[0] = [255, 0, 0];
+        gif.write_frame(&checkered)?;
+
+        let mut colorful = Image::new(20, 15, BLACK);
+        for (ix, pixel) in colorful.pixels.iter_mut().enumerate() {
+            *pixel = [ix as u8, (ix / 256) as u8, 0];
+        }
+        assert!(matches!(
+            gif.write_frame(&colorful),
+            Err(ImageError::FrameSize {
+                expected: (2, 2),
+                found: (20, 15)
+            })
+        ));
+
+        let out = gif.finish()?;
+        assert_eq!(&out[..6], b"GIF89a");
+        assert_eq!(out.last(), Some(&0x3b), "ends with the trailer");
+
+        // 300 colors don't fit in a palette
+        let mut gif = GifWriter::new(vec![], 20, 15, Duration::ZERO)?;
+        gif.write_frame(&colorful)?;
+
+        assert!(matches!(
+            GifWriter::new(vec![], 70_000, 1, Duration::ZERO),
+            Err(ImageError::TooLarge(70_000, 1))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_format() {
+        let err = Image::new(1, 1, WHITE).save("image.jpg").unwrap_err();
+        assert!(matches!(err, ImageError::UnknownFormat(_)));
+    }
+
+    #[test]
+    fn heat_colors() {
+        assert_eq!(heat(0.0), [0, 0, 255]);
+        assert_eq!(heat(0.5), [0, 255, 0]);
+        assert_eq!(heat(1.0), [255, 0, 0]);
+        assert_eq!(heat(2.0), heat(1.0));
+    }
+}
@@ -0,0 +1,7 @@
+//! Data structures shared by the solutions
+
+pub mod grid;
+pub mod image;
+pub mod ocr;
+pub mod search;
+pub mod vector;
@@ -0,0 +1,409 @@
+//! Read the capital letters drawn by some puzzles with `#`
+//!
+//! Puzzles use two fonts: [`SMALL`], 4 pixels wide and 6 tall, and [`LARGE`], 6 pixels wide
+//! and 10 tall. Any character other than `#` is an unlit pixel.
+
+/// A fixed width font of capital letters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Font {
+    pub width: usize,
+    pub height: usize,
+    /// Unlit columns between two glyphs
+    pub spacing: usize,
+    /// Each letter with its rows
+    glyphs: &'static [(char, &'static [&'static str])],
+}
+
+/// The font 4 pixels wide and 6 tall
+pub const SMALL: Font = Font {
+    width: 4,
+    height: 6,
+    spacing: 1,
+    glyphs: &[
+        ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+        ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+        ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+        ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+        ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+        ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+        ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+        ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+        ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+        ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+        ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+        ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+        ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+        ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+    ],
+};
+
+/// The font 6 pixels wide and 10 tall
+#[rustfmt::skip]
+pub const LARGE: Font = Font {
+    width: 6,
+    height: 10,
+    spacing: 2,
+    glyphs: &[
+        ('A', &[
+            "..##..",
+            ".#..#.",
+            "#....#",
+            "#....#",
+            "#....#",
+            "######",
+            "#....#",
+            "#....#",
+            "#....#",
+            "#....#",
+        ]),
+        ('B', &[
+            "#####.",
+            "#....#",
+            "#....#",
+            "#....#",
+            "#####.",
+            "#....#",
+            "#....#",
+            "#....#",
+            "#....#",
+            "#####.",
+        ]),
+        ('C', &[
+            ".####.",
+            "#....#",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#....#",
+            ".####.",
+        ]),
+        ('E', &[
+            "######",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#####.",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "######",
+        ]),
+        ('F', &[
+            "######",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#####.",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+        ]),
+        ('G', &[
+            ".####.",
+            "#....#",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#..###",
+            "#....#",
+            "#....#",
+            "#...##",
+            ".###.#",
+        ]),
+        ('H', &[
+            "#....#",
+            "#....#",
+            "#....#",
+            "#....#",
+            "######",
+            "#....#",
+            "#....#",
+            "#....#",
+            "#....#",
+            "#....#",
+        ]),
+        ('J', &[
+            "...###",
+            "....#.",
+            "....#.",
+            "....#.",
+            "....#.",
+            "....#.",
+            "....#.",
+            "#...#.",
+            "#...#.",
+            ".###..",
+        ]),
+        ('K', &[
+            "#....#",
+            "#...#.",
+            "#..#..",
+            "#.#...",
+            "##....",
+            "##....",
+            "#.#...",
+            "#..#..",
+            "#...#.",
+            "#....#",
+        ]),
+        ('L', &[
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "######",
+        ]),
+        ('N', &[
+            "#....#",
+            "##...#",
+            "##...#",
+            "#.#..#",
+            "#.#..#",
+            "#..#.#",
+            "#..#.#",
+            "#...##",
+            "#...##",
+            "#....#",
+        ]),
+        ('P', &[
+            "#####.",
+            "#....#",
+            "#....#",
+            "#....#",
+            "#####.",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+        ]),
+        ('R', &[
+            "#####.",
+            "#....#",
+            "#....#",
+            "#....#",
+            "#####.",
+            "#..#..",
+            "#...#.",
+            "#...#.",
+            "#....#",
+            "#....#",
+        ]),
+        ('X', &[
+            "#....#",
+            "#....#",
+            ".#..#.",
+            ".#..#.",
+            "..##..",
+            "..##..",
+            ".#..#.",
+            ".#..#.",
+            "#....#",
+            "#....#",
+        ]),
+        ('Z', &[
+            "######",
+            ".....#",
+            ".....#",
+            "....#.",
+            "...#..",
+            "..#...",
+            ".#....",
+            "#.....",
+            "#.....",
+            "######",
+        ]),
+    ],
+};
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum OcrError {
+    #[error("the screen is {found} rows tall, the font is {expected}")]
+    Height { expected: usize, found: usize },
+    #[error("unrecognized glyphs at positions {} of {read:?}", join(.positions))]
+    Unrecognized {
+        /// Index of each unrecognized glyph, from the left
+        positions: Vec<usize>,
+        /// The letters read, with a `?` for each unrecognized glyph
+        read: String,
+    },
+}
+
+fn join(positions: &[usize]) -> String {
+    positions
+        .iter()
+        .map(|pos| pos.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl Font {
+    /// The letter drawn by `pixels`, row by row
+    fn letter(&self, pixels: &[bool]) -> Option<char> {
+        self.glyphs
+            .iter()
+            .find(|(_, glyph)| {
+                glyph
+                    .iter()
+                    .flat_map(|row| row.bytes())
+                    .map(|b| b == b'#')
+                    .eq(pixels.iter().copied())
+            })
+            .map(|&(letter, _)| letter)
+    }
+
+    /// The rows drawing `letter`, if the font has it
+    pub fn glyph(&self, letter: char) -> Option<&'static [&'static str]> {
+        self.glyphs
+            .iter()
+            .find(|&&(c, _)| c == letter)
+            .map(|&(_, glyph)| glyph)
+    }
+}
+
+/// Read the letters on `screen` drawn with `font`
+///
+/// Trailing empty lines are ignored and rows can be shorter than the others, the missing
+/// pixels are unlit.
+pub fn read(screen: &str, font: &Font) -> Result<String, OcrError> {
+    let rows: Vec<&[u8]> = screen.trim_end().lines().map(str::as_bytes).collect();
+    if rows.len() != font.height {
+        return Err(OcrError::Height {
+            expected: font.height,
+            found: rows.len(),
+        });
+    }
+
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    let stride = font.width + font.spacing;
+    let count = width.div_ceil(stride);
+
+    let mut read = String::new();
+    let mut positions = vec![];
+    for ix in 0..count {
+        let columns = ix * stride..ix * stride + font.width;
+        let pixels: Vec<bool> = rows
+            .iter()
+            .flat_map(|row| columns.clone().map(|x| row.get(x) == Some(&b'#')))
+            .collect();
+
+        match font.letter(&pixels) {
+            Some(letter) => read.push(letter),
+            None => {
+                positions.push(ix);
+                read.push('?');
+            }
+        }
+    }
+
+    if positions.is_empty() {
+        Ok(read)
+    } else {
+        Err(OcrError::Unrecognized { positions, read })
+    }
+}
+
+/// Read the letters on `screen`, with the font matching its height
+pub fn read_any(screen: &str) -> Result<String, OcrError> {
+    let height = screen.trim_end().lines().count();
+    let font = if height == LARGE.height {
+        &LARGE
+    } else {
+        &SMALL
+    };
+    read(screen, font)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ocr::{read, read_any, Font, OcrError, LARGE, SMALL};
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    /// Draw `text` like the puzzles do
+    fn draw(text: &str, font: &Font) -> String {
+        let spacing = ".".repeat(font.spacing);
+        let glyphs: Vec<_> = text.chars().map(|c| font.glyph(c).unwrap()).collect();
+        (0..font.height)
+            .map(|row| {
+                let row: Vec<_> = glyphs.iter().map(|glyph| glyph[row]).collect();
+                row.join(&spacing) + &spacing + "\n"
+            })
+            .collect()
+    }
+
+    #[test]
+    fn glyphs_are_complete() {
+        for font in [SMALL, LARGE] {
+            for &(letter, glyph) in font.glyphs {
+                assert_eq!(glyph.len(), font.height, "{letter}");
+                assert!(glyph.iter().all(|row| row.len() == font.width), "{letter}");
+            }
+        }
+    }
+
+    #[test]
+    fn small_font() {
+        let screen = draw("PLPAFBCL", &SMALL);
+        assert_eq!(
+            screen.lines().next(),
+            Some("###..#....###...##..####.###...##..#....")
+        );
+        assert_eq!(read(&screen, &SMALL), Ok("PLPAFBCL".to_string()));
+        assert_eq!(read_any(&screen), Ok("PLPAFBCL".to_string()));
+
+        let all: String = SMALL.glyphs.iter().map(|&(c, _)| c).collect();
+        assert_eq!(read(&draw(&all, &SMALL), &SMALL), Ok(all));
+    }
+
+    #[test]
+    fn large_font() {
+        let all: String = LARGE.glyphs.iter().map(|&(c, _)| c).collect();
+        let screen = draw(&all, &LARGE);
+
+        assert_eq!(read(&screen, &LARGE), Ok(all.clone()));
+        assert_eq!(read_any(&screen), Ok(all));
+    }
+
+    #[test]
+    fn unrecognized() {
+        let mut rows: Vec<String> = draw("HEZ", &SMALL).lines().map(String::from).collect();
+        // Remove the middle bar of the E
+        rows[2].replace_range(5..8, "...");
+        let screen = rows.join("\n");
+
+        let err = read(&screen, &SMALL).unwrap_err();
+        assert_eq!(
+            err,
+            OcrError::Unrecognized {
+                positions: vec![1],
+                read: "H?Z".to_string()
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "unrecognized glyphs at positions 1 of \"H?Z\""
+        );
+
+        assert_eq!(
+            read("#..#\n", &SMALL),
+            Err(OcrError::Height {
+                expected: 6,
+                found: 1
+            })
+        );
+    }
+}
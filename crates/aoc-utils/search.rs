@@ -0,0 +1,395 @@
+//! Path finding over implicit graphs
+//!
+//! The graphs are never built: each search takes a `successors` function returning the
+//! neighbors of a node (and the cost of moving there for weighted searches) and a `goal`
+//! predicate, so nodes can be anything hashable, like a position on a
+//! [`Grid`](crate::grid::Grid) or the whole state of a puzzle.
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The cheapest path found by a search
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    /// The nodes from the start to the goal, both included
+    pub nodes: Vec<N>,
+    pub cost: C,
+    /// How many nodes were expanded before reaching the goal
+    pub visited: usize,
+}
+
+impl<N, C> Path<N, C> {
+    pub fn start(&self) -> &N {
+        self.nodes.first().expect("paths aren't empty")
+    }
+
+    pub fn goal(&self) -> &N {
+        self.nodes.last().expect("paths aren't empty")
+    }
+
+    /// Number of moves from the start to the goal
+    pub fn len(&self) -> usize {
+        self.nodes.len() - 1
+    }
+
+    /// A path without moves, the start is a goal
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// The nodes reached by a search and how they were reached, to rebuild the paths
+struct Explored<N> {
+    /// Each node with the index of the node it was reached from
+    nodes: Vec<(N, Option<usize>)>,
+    index: HashMap<N, usize>,
+}
+
+impl<N: Clone + Eq + Hash> Explored<N> {
+    fn new() -> Self {
+        Self {
+            nodes: vec![],
+            index: HashMap::new(),
+        }
+    }
+
+    fn insert(&mut self, node: N, parent: Option<usize>) -> usize {
+        let ix = self.nodes.len();
+        self.index.insert(node.clone(), ix);
+        self.nodes.push((node, parent));
+        ix
+    }
+
+    fn path_to(&self, mut ix: usize) -> Vec<N> {
+        let mut path = vec![];
+        loop {
+            let (node, parent) = &self.nodes[ix];
+            path.push(node.clone());
+            match parent {
+                Some(parent) => ix = *parent,
+                None => break,
+            }
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Breadth first search from `start` to the closest node matching `goal`
+pub fn bfs<N, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    bfs_multi([start], successors, goal)
+}
+
+/// Breadth first search from whichever of the `starts` is closest to a node matching `goal`
+pub fn bfs_multi<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut explored = Explored::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !explored.index.contains_key(&start) {
+            queue.push_back(explored.insert(start, None));
+        }
+    }
+
+    let mut visited = 0;
+    while let Some(ix) = queue.pop_front() {
+        visited += 1;
+
+        let node = explored.nodes[ix].0.clone();
+        if goal(&node) {
+            let nodes = explored.path_to(ix);
+            return Some(Path {
+                cost: nodes.len() - 1,
+                nodes,
+                visited,
+            });
+        }
+
+        for next in successors(&node) {
+            if !explored.index.contains_key(&next) {
+                queue.push_back(explored.insert(next, Some(ix)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Cheapest path from `start` to a node matching `goal`, moves can't have negative costs
+///
+/// `C::default()` is the cost of not moving at all.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), goal)
+}
+
+/// Cheapest path from `start` to a node matching `goal`, guided by a `heuristic`
+///
+/// The path is only guaranteed to be the cheapest if the `heuristic` never overestimates
+/// the cost to reach a goal.
+pub fn astar<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let search = weighted(start, successors, heuristic, goal);
+    let ix = search.goal?;
+    Some(Path {
+        nodes: search.explored.path_to(ix),
+        cost: search.costs[ix],
+        visited: search.visited,
+    })
+}
+
+/// The cheapest costs from the start of a search to every node it reached
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Costs<N: Eq + Hash, C> {
+    pub costs: HashMap<N, C>,
+    /// How many nodes were expanded
+    pub visited: usize,
+}
+
+impl<N: Eq + Hash, C: Copy> Costs<N, C> {
+    /// The cheapest cost to `node`, `None` if it can't be reached
+    pub fn get(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+}
+
+/// The cheapest cost from `start` to every node reachable from it
+pub fn dijkstra_costs<N, C, I>(start: N, successors: impl FnMut(&N) -> I) -> Costs<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let search = weighted(start, successors, |_| C::default(), |_| false);
+    Costs {
+        costs: search
+            .explored
+            .nodes
+            .into_iter()
+            .map(|(node, _)| node)
+            .zip(search.costs)
+            .collect(),
+        visited: search.visited,
+    }
+}
+
+/// Where a weighted search stopped
+struct Weighted<N, C> {
+    explored: Explored<N>,
+    /// The cheapest known cost to each explored node
+    costs: Vec<C>,
+    visited: usize,
+    /// The index of the goal, if one was reached
+    goal: Option<usize>,
+}
+
+/// Expand the nodes from `start` in order of cost plus `heuristic` until reaching a `goal`
+fn weighted<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Weighted<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut explored = Explored::new();
+    let mut costs = vec![];
+    let mut queue = BinaryHeap::new();
+
+    let ix = explored.insert(start.clone(), None);
+    costs.push(C::default());
+    queue.push(Reverse((heuristic(&start), C::default(), ix)));
+
+    let mut visited = 0;
+    while let Some(Reverse((_, cost, ix))) = queue.pop() {
+        // Already reached through a cheaper path
+        if cost > costs[ix] {
+            continue;
+        }
+        visited += 1;
+
+        let node = explored.nodes[ix].0.clone();
+        if goal(&node) {
+            return Weighted {
+                explored,
+                costs,
+                visited,
+                goal: Some(ix),
+            };
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            let next_ix = match explored.index.entry(next) {
+                Entry::Occupied(entry) => {
+                    let next_ix = *entry.get();
+                    if costs[next_ix] <= next_cost {
+                        continue;
+                    }
+                    costs[next_ix] = next_cost;
+                    explored.nodes[next_ix].1 = Some(ix);
+                    next_ix
+                }
+                Entry::Vacant(entry) => {
+                    let next_ix = explored.nodes.len();
+                    explored.nodes.push((entry.key().clone(), Some(ix)));
+                    entry.insert(next_ix);
+                    costs.push(next_cost);
+                    next_ix
+                }
+            };
+
+            let estimate = next_cost + heuristic(&explored.nodes[next_ix].0);
+            queue.push(Reverse((estimate, next_cost, next_ix)));
+        }
+    }
+
+    Weighted {
+        explored,
+        costs,
+        visited,
+        goal: None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::search::{astar, bfs, bfs_multi, dijkstra, dijkstra_costs};
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    /// A 5x5 maze, `#` are walls
+    static MAZE: [&str; 5] = [".....", ".###.", "...#.", "##.#.", "....."];
+
+    fn open(x: i32, y: i32) -> bool {
+        (0..5).contains(&x)
+            && (0..5).contains(&y)
+            && MAZE[y as usize].as_bytes()[x as usize] == b'.'
+    }
+
+    fn moves(&(x, y): &(i32, i32)) -> Vec<(i32, i32)> {
+        [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(|&(x, y)| open(x, y))
+            .collect()
+    }
+
+    #[test]
+    fn shortest_path() {
+        let path = bfs((0, 0), moves, |&node| node == (4, 4)).unwrap();
+
+        assert_eq!(path.cost, 8);
+        assert_eq!(path.len(), 8);
+        assert_eq!(path.start(), &(0, 0));
+        assert_eq!(path.goal(), &(4, 4));
+        for (a, b) in path.nodes.iter().zip(&path.nodes[1..]) {
+            assert!(moves(a).contains(b), "{a:?} -> {b:?}");
+        }
+    }
+
+    #[test]
+    fn unreachable() {
+        assert_eq!(bfs((0, 0), moves, |&node| node == (2, 1)), None);
+        assert_eq!(
+            dijkstra(
+                (0, 0),
+                |n| moves(n).into_iter().map(|n| (n, 1)),
+                |&n| n == (2, 1)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn start_is_goal() {
+        let path = bfs((0, 0), moves, |_| true).unwrap();
+
+        assert!(path.is_empty());
+        assert_eq!(path.nodes, [(0, 0)]);
+        assert_eq!(path.visited, 1);
+    }
+
+    #[test]
+    fn multiple_starts() {
+        let path = bfs_multi([(0, 0), (0, 4)], moves, |&node| node == (2, 3)).unwrap();
+
+        assert_eq!(path.start(), &(0, 4));
+        assert_eq!(path.cost, 3);
+    }
+
+    #[test]
+    fn weighted() {
+        // Going down is expensive, so the path goes down as little as possible
+        let successors = |n: &(i32, i32)| {
+            let from = *n;
+            moves(n)
+                .into_iter()
+                .map(move |to| (to, if to.1 > from.1 { 5 } else { 1 }))
+        };
+
+        let path = dijkstra((0, 2), successors, |&n| n == (4, 4)).unwrap();
+        assert_eq!(path.start(), &(0, 2));
+        assert_eq!(path.cost, 2 + 2 * 5 + 2);
+        assert_eq!(path.nodes[2..5], [(2, 2), (2, 3), (2, 4)]);
+
+        let manhattan = |&(x, y): &(i32, i32)| (4 - x).abs() + (4 - y).abs();
+        let guided = astar((0, 2), successors, manhattan, |&n| n == (4, 4)).unwrap();
+        assert_eq!(guided.cost, path.cost);
+        assert!(guided.visited <= path.visited);
+    }
+
+    #[test]
+    fn all_costs() {
+        let successors = |n: &(i32, i32)| moves(n).into_iter().map(|n| (n, 1));
+        let costs = dijkstra_costs((0, 0), successors);
+
+        // Every cell but the 7 walls is reachable, and expanded once
+        assert_eq!(costs.costs.len(), 25 - 7);
+        assert_eq!(costs.visited, 25 - 7);
+        assert_eq!(costs.get(&(0, 0)), Some(0));
+        assert_eq!(costs.get(&(4, 4)), Some(8));
+        assert_eq!(costs.get(&(2, 1)), None);
+        for (node, cost) in &costs.costs {
+            let path = bfs((0, 0), moves, |n| n == node).unwrap();
+            assert_eq!(path.cost, *cost, "{node:?}");
+        }
+    }
+}
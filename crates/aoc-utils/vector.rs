@@ -0,0 +1,388 @@
+//! 2D and 3D vectors, used both as points and as offsets between them
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
+};
+
+use nom::{character::complete::char, error::ParseError, IResult, Parser};
+use num::Signed;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// Operations applied to each component
+macro_rules! componentwise {
+    ($vec:ident { $($field:ident),+ }, $tuple:ty) => {
+        impl<T> $vec<T> {
+            pub const fn new($($field: T),+) -> Self {
+                Self { $($field),+ }
+            }
+
+            pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> $vec<U> {
+                $vec { $($field: f(self.$field)),+ }
+            }
+        }
+
+        impl<T: Ord> $vec<T> {
+            /// The smallest value of each component
+            pub fn componentwise_min(self, other: Self) -> Self {
+                Self { $($field: self.$field.min(other.$field)),+ }
+            }
+
+            /// The largest value of each component
+            pub fn componentwise_max(self, other: Self) -> Self {
+                Self { $($field: self.$field.max(other.$field)),+ }
+            }
+        }
+
+        impl<T: Signed + Copy> $vec<T> {
+            pub fn abs(self) -> Self {
+                self.map(|n| n.abs())
+            }
+
+            /// A vector of `-1`, `0` and `1`, one step towards the direction of this one
+            pub fn signum(self) -> Self {
+                self.map(|n| n.signum())
+            }
+
+            /// Moves to go from `self` to `other` along the axes
+            pub fn manhattan(self, other: Self) -> T {
+                let d = (other - self).abs();
+                T::zero() $(+ d.$field)+
+            }
+        }
+
+        impl<T: Signed + Copy + Ord> $vec<T> {
+            /// Moves to go from `self` to `other` when diagonal moves are allowed
+            pub fn chebyshev(self, other: Self) -> T {
+                let d = (other - self).abs();
+                T::zero() $(.max(d.$field))+
+            }
+        }
+
+        impl<T> From<$tuple> for $vec<T> {
+            fn from(($($field),+): $tuple) -> Self {
+                Self { $($field),+ }
+            }
+        }
+
+        impl<T> From<$vec<T>> for $tuple {
+            fn from(v: $vec<T>) -> Self {
+                ($(v.$field),+)
+            }
+        }
+
+        impl<T: Add<Output = T>> Add for $vec<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Self { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $vec<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $vec<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$field += rhs.$field;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $vec<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$field -= rhs.$field;)+
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $vec<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        impl<T: Mul<Output = T> + Copy> Mul<T> for $vec<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self::Output {
+                Self { $($field: self.$field * rhs),+ }
+            }
+        }
+
+        impl<T: Div<Output = T> + Copy> Div<T> for $vec<T> {
+            type Output = Self;
+
+            fn div(self, rhs: T) -> Self::Output {
+                Self { $($field: self.$field / rhs),+ }
+            }
+        }
+    };
+}
+
+componentwise!(Vec2 { x, y }, (T, T));
+componentwise!(Vec3 { x, y, z }, (T, T, T));
+
+impl<T: Signed + Copy> Vec2<T> {
+    /// Unit steps along each axis: `-y`, `+x`, `+y` and `-x`
+    ///
+    /// In a [`Grid`](crate::grid::Grid), where `y` grows downwards, that is up, right, down
+    /// and left.
+    pub fn directions4() -> [Self; 4] {
+        let (zero, one) = (T::zero(), T::one());
+        [
+            Self::new(zero, -one),
+            Self::new(one, zero),
+            Self::new(zero, one),
+            Self::new(-one, zero),
+        ]
+    }
+
+    /// Unit steps along each axis and diagonal, starting from `(-1, -1)`
+    ///
+    /// Clockwise from the top left in a [`Grid`](crate::grid::Grid).
+    pub fn directions8() -> [Self; 8] {
+        let (zero, one) = (T::zero(), T::one());
+        [
+            Self::new(-one, -one),
+            Self::new(zero, -one),
+            Self::new(one, -one),
+            Self::new(one, zero),
+            Self::new(one, one),
+            Self::new(zero, one),
+            Self::new(-one, one),
+            Self::new(-one, zero),
+        ]
+    }
+
+    pub fn neighbors4(self) -> [Self; 4] {
+        Self::directions4().map(|d| self + d)
+    }
+
+    pub fn neighbors8(self) -> [Self; 8] {
+        Self::directions8().map(|d| self + d)
+    }
+
+    /// Rotate a quarter turn from `+x` towards `+y`, clockwise in a [`Grid`](crate::grid::Grid)
+    /// since `y` grows downwards
+    pub fn rotate_cw(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotate a quarter turn from `+y` towards `+x`, counterclockwise in a
+    /// [`Grid`](crate::grid::Grid)
+    pub fn rotate_ccw(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+}
+
+impl<T: Signed + Copy> Vec3<T> {
+    /// Unit steps along each axis: `+x`, `-x`, `+y`, `-y`, `+z` and `-z`
+    pub fn directions6() -> [Self; 6] {
+        let (zero, one) = (T::zero(), T::one());
+        [
+            Self::new(one, zero, zero),
+            Self::new(-one, zero, zero),
+            Self::new(zero, one, zero),
+            Self::new(zero, -one, zero),
+            Self::new(zero, zero, one),
+            Self::new(zero, zero, -one),
+        ]
+    }
+
+    /// Steps to all the cubes touching a cube, even by a corner
+    pub fn directions26() -> [Self; 26] {
+        let steps = [-T::one(), T::zero(), T::one()];
+        let zero = T::zero();
+        let mut directions = [Self::new(zero, zero, zero); 26];
+        let mut ix = 0;
+        for x in steps {
+            for y in steps {
+                for z in steps {
+                    if (x, y, z) != (zero, zero, zero) {
+                        directions[ix] = Self::new(x, y, z);
+                        ix += 1;
+                    }
+                }
+            }
+        }
+        directions
+    }
+
+    pub fn neighbors6(self) -> [Self; 6] {
+        Self::directions6().map(|d| self + d)
+    }
+
+    pub fn neighbors26(self) -> [Self; 26] {
+        Self::directions26().map(|d| self + d)
+    }
+
+    /// Rotate a quarter turn around the `x` axis, from `+y` towards `+z`
+    pub fn rotate_x(self) -> Self {
+        Self::new(self.x, -self.z, self.y)
+    }
+
+    /// Rotate a quarter turn around the `y` axis, from `+z` towards `+x`
+    pub fn rotate_y(self) -> Self {
+        Self::new(self.z, self.y, -self.x)
+    }
+
+    /// Rotate a quarter turn around the `z` axis, from `+x` towards `+y`
+    pub fn rotate_z(self) -> Self {
+        Self::new(-self.y, self.x, self.z)
+    }
+}
+
+/// Formatted like the puzzle inputs, `x,y`
+impl<T: Display> Display for Vec2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// Formatted like the puzzle inputs, `x,y,z`
+impl<T: Display> Display for Vec3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+/// Parse a [`Vec2`] as `x,y`, with `coord` parsing each component
+pub fn vec2<'a, T, E: ParseError<&'a str>>(
+    mut coord: impl Parser<&'a str, T, E>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec2<T>, E> {
+    move |input| {
+        let (input, x) = coord.parse(input)?;
+        let (input, _) = char(',')(input)?;
+        let (input, y) = coord.parse(input)?;
+        Ok((input, Vec2 { x, y }))
+    }
+}
+
+/// Parse a [`Vec3`] as `x,y,z`, with `coord` parsing each component
+pub fn vec3<'a, T, E: ParseError<&'a str>>(
+    mut coord: impl Parser<&'a str, T, E>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec3<T>, E> {
+    move |input| {
+        let (input, x) = coord.parse(input)?;
+        let (input, _) = char(',')(input)?;
+        let (input, y) = coord.parse(input)?;
+        let (input, _) = char(',')(input)?;
+        let (input, z) = coord.parse(input)?;
+        Ok((input, Vec3 { x, y, z }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::vector::{vec2, vec3, Vec2, Vec3};
+    use aoc_runner::parse::complete;
+    use color_eyre::Result;
+    use nom::character::complete::i32;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn arithmetic() {
+        let mut a = Vec2::new(1, -2);
+        let b = Vec2::new(3, 4);
+
+        assert_eq!(a + b, Vec2::new(4, 2));
+        assert_eq!(a - b, Vec2::new(-2, -6));
+        assert_eq!(-a, Vec2::new(-1, 2));
+        assert_eq!(b * 2, Vec2::new(6, 8));
+        assert_eq!(b / 2, Vec2::new(1, 2));
+
+        a += b;
+        assert_eq!(a, Vec2::new(4, 2));
+        a -= b;
+        assert_eq!(a, Vec2::new(1, -2));
+
+        assert_eq!(
+            Vec3::new(1, 2, 3) + Vec3::from((1, 1, 1)),
+            Vec3::new(2, 3, 4)
+        );
+        assert_eq!(<(i32, i32)>::from(b), (3, 4));
+    }
+
+    #[test]
+    fn distances() {
+        let a = Vec2::new(1, -2);
+        let b = Vec2::new(3, 4);
+
+        assert_eq!(a.manhattan(b), 8);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!((b - a).signum(), Vec2::new(1, 1));
+        assert_eq!(Vec3::new(0, 0, 0).manhattan(Vec3::new(1, -2, 3)), 6);
+        assert_eq!(Vec3::new(0, 0, 0).chebyshev(Vec3::new(1, -2, 3)), 3);
+        assert_eq!(a.componentwise_min(b), Vec2::new(1, -2));
+        assert_eq!(Vec2::new(5, 0).componentwise_max(b), Vec2::new(5, 4));
+    }
+
+    #[test]
+    fn rotations() {
+        let v = Vec2::new(2, 1);
+
+        assert_eq!(v.rotate_cw(), Vec2::new(-1, 2));
+        assert_eq!(v.rotate_ccw(), Vec2::new(1, -2));
+        assert_eq!(v.rotate_cw().rotate_ccw(), v);
+        // Right turns to down, then left, in a grid
+        let right = Vec2::new(1, 0);
+        assert_eq!(right.rotate_cw(), Vec2::new(0, 1));
+        assert_eq!(right.rotate_cw().rotate_cw(), Vec2::new(-1, 0));
+
+        let x = Vec3::new(1, 0, 0);
+        let y = Vec3::new(0, 1, 0);
+        let z = Vec3::new(0, 0, 1);
+        assert_eq!(x.rotate_z(), y);
+        assert_eq!(y.rotate_x(), z);
+        assert_eq!(z.rotate_y(), x);
+    }
+
+    #[test]
+    fn neighbors() {
+        let v = Vec2::new(0, 0);
+
+        assert_eq!(v.neighbors4().into_iter().collect::<HashSet<_>>().len(), 4);
+        assert!(v.neighbors4().iter().all(|n| n.manhattan(v) == 1));
+        assert!(v.neighbors8().iter().all(|n| n.chebyshev(v) == 1));
+
+        let v = Vec3::new(1, 1, 1);
+        assert!(v.neighbors6().iter().all(|n| n.manhattan(v) == 1));
+        assert_eq!(
+            v.neighbors26().into_iter().collect::<HashSet<_>>().len(),
+            26
+        );
+        assert!(v.neighbors26().iter().all(|n| n.chebyshev(v) == 1));
+    }
+
+    #[test]
+    fn parse() -> Result<()> {
+        assert_eq!(complete(vec2(i32), "498,-4")?, Vec2::new(498, -4));
+        assert_eq!(complete(vec3(i32), "2,2,5")?, Vec3::new(2, 2, 5));
+        assert!(complete(vec3(i32), "2,2").is_err());
+
+        assert_eq!(Vec2::new(498, -4).to_string(), "498,-4");
+        assert_eq!(Vec3::new(2, 2, 5).to_string(), "2,2,5");
+
+        Ok(())
+    }
+}
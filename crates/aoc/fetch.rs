@@ -0,0 +1,281 @@
+//! Download puzzle inputs, keeping a copy of each so it's only ever downloaded once
+//!
+//! Inputs are cached as `YYYY/NN` inside the cache directory, `$AOC_CACHE_DIR` if set or
+//! `~/.cache/aoc` otherwise. The session cookie is read from `$AOC_SESSION` or the `session`
+//! file at the root of the repo.
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use color_eyre::{
+    eyre::{bail, eyre, WrapErr},
+    Help, Result, SectionExt,
+};
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const BASE_URL: &str = "https://adventofcode.com";
+/// Identify ourselves as requested by the Advent of Code maintainers
+const USER_AGENT: &str = concat!(
+    "aoc-runner/",
+    env!("CARGO_PKG_VERSION"),
+    " (",
+    env!("CARGO_PKG_AUTHORS"),
+    ")"
+);
+
+pub struct Fetcher {
+    base_url: String,
+    cache_dir: PathBuf,
+    session: Option<String>,
+}
+
+impl Fetcher {
+    /// A fetcher with the default cache and session
+    ///
+    /// Downloads from adventofcode.com, or from `$AOC_BASE_URL` if set (e.g. a local stand-in).
+    pub fn new(repo_root: &Path) -> Result<Self> {
+        Ok(Self {
+            base_url: std::env::var(BASE_URL_VAR).unwrap_or_else(|_| BASE_URL.to_string()),
+            cache_dir: default_cache_dir()?,
+            session: session(&repo_root.join("session"))?,
+        })
+    }
+
+    /// Where the input of `day` of `year` is cached
+    pub fn cached_path(&self, year: u32, day: u32) -> PathBuf {
+        self.cache_dir.join(format!("{year}/{day:02}"))
+    }
+
+    /// Path to the input of `day` of `year`, downloading it if it isn't cached yet
+    pub fn fetch(&self, year: u32, day: u32) -> Result<PathBuf> {
+        let path = self.cached_path(year, day);
+        if path.exists() {
+            return Ok(path);
+        }
+
+        let input = self.download(year, day)?;
+        let dir = path
+            .parent()
+            .expect("cached inputs are inside a year directory");
+        std::fs::create_dir_all(dir)
+            .wrap_err_with(|| format!("couldn't create {}", dir.display()))?;
+        // Write it next to the cache and move it in place, so an interrupted write isn't cached
+        let partial = dir.join(format!(".{day:02}.{}.part", std::process::id()));
+        std::fs::write(&partial, input)
+            .wrap_err_with(|| format!("couldn't write input to {}", partial.display()))?;
+        std::fs::rename(&partial, &path)
+            .wrap_err_with(|| format!("couldn't move the input to {}", path.display()))?;
+
+        Ok(path)
+    }
+
+    fn download(&self, year: u32, day: u32) -> Result<String> {
+        let Some(session) = &self.session else {
+            return Err(eyre!("no session cookie to download the input with")).with_suggestion(
+                || format!("set {SESSION_VAR} or write the cookie to the `session` file"),
+            );
+        };
+
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let response = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build()
+            .get(&url)
+            .set("Cookie", &format!("session={session}"))
+            .call();
+
+        match response {
+            Ok(response) => response
+                .into_string()
+                .wrap_err_with(|| format!("couldn't read the response from {url}")),
+            Err(ureq::Error::Status(404, _)) => Err(eyre!("day {day} of {year} isn't available"))
+                .with_suggestion(|| "wait for the puzzle to unlock"),
+            Err(ureq::Error::Status(code @ (400 | 401), response)) => {
+                let body = response.into_string().unwrap_or_default();
+                Err(eyre!("the server rejected the request ({code})"))
+                    .with_section(|| body.trim().to_string().header("Response:"))
+                    .with_suggestion(|| "the session cookie has probably expired")
+            }
+            Err(ureq::Error::Status(code @ 500..=599, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                Err(eyre!("the server failed to answer ({code})"))
+                    .with_section(|| body.trim().to_string().header("Response:"))
+                    .with_suggestion(|| "it's a server error, retry later")
+            }
+            Err(err) => Err(err).wrap_err_with(|| format!("couldn't download {url}")),
+        }
+    }
+}
+
+fn default_cache_dir() -> Result<PathBuf> {
+    if let Some(dir) = std::env::var_os(CACHE_DIR_VAR) {
+        return Ok(dir.into());
+    }
+    if let Some(dir) = std::env::var_os("XDG_CACHE_HOME") {
+        return Ok(Path::new(&dir).join("aoc"));
+    }
+    match std::env::var_os("HOME") {
+        Some(home) => Ok(Path::new(&home).join(".cache/aoc")),
+        None => bail!("couldn't find a cache directory, set {CACHE_DIR_VAR}"),
+    }
+}
+
+/// The session cookie from `$AOC_SESSION` or `file`, if any
+fn session(file: &Path) -> Result<Option<String>> {
+    let session = match std::env::var(SESSION_VAR) {
+        Ok(session) => session,
+        Err(_) if file.exists() => std::fs::read_to_string(file)
+            .wrap_err_with(|| format!("couldn't read the session from {}", file.display()))?,
+        Err(_) => return Ok(None),
+    };
+
+    // Accept both the bare cookie and `session=...` as used by `curl -b`
+    let session = session.trim();
+    Ok(Some(
+        session
+            .strip_prefix("session=")
+            .unwrap_or(session)
+            .to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        path::PathBuf,
+        thread::JoinHandle,
+    };
+
+    use crate::fetch::{session, Fetcher, USER_AGENT};
+    use color_eyre::Result;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    /// Serve `response` to a single request, returning the request's head
+    fn serve_once(response: &'static str) -> Result<(String, JoinHandle<String>)> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let url = format!("http://{}", listener.local_addr()?);
+
+        let server = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().expect("client connects");
+            let mut reader = BufReader::new(stream);
+            let mut head = String::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).expect("read request");
+                if line.trim().is_empty() {
+                    break;
+                }
+                head.push_str(&line);
+            }
+            reader
+                .get_mut()
+                .write_all(response.as_bytes())
+                .expect("write response");
+            head
+        });
+
+        Ok((url, server))
+    }
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn fetcher(url: String, cache_dir: PathBuf) -> Fetcher {
+        Fetcher {
+            base_url: url,
+            cache_dir,
+            session: Some("cookie".to_string()),
+        }
+    }
+
+    #[test]
+    fn download_and_cache() -> Result<()> {
+        let (url, server) = serve_once("HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\n1\n2\n")?;
+        let fetcher = fetcher(url, cache_dir("aoc-fetch-cache"));
+
+        let path = fetcher.fetch(2022, 1)?;
+        assert_eq!(path, fetcher.cached_path(2022, 1));
+        assert_eq!(std::fs::read_to_string(&path)?, "1\n2\n");
+        // Nothing else is left in the cache
+        let cached: Vec<_> = std::fs::read_dir(path.parent().unwrap())?
+            .map(|entry| entry.map(|entry| entry.file_name()))
+            .collect::<Result<_, _>>()?;
+        assert_eq!(cached, ["01"]);
+
+        let head = server.join().expect("server finishes");
+        assert!(head.starts_with("GET /2022/day/1/input HTTP/1.1"), "{head}");
+        let head = head.to_lowercase();
+        assert!(head.contains(&format!("user-agent: {}", USER_AGENT.to_lowercase())));
+        assert!(head.contains("cookie: session=cookie"), "{head}");
+
+        // The server is gone, so this only works if it isn't downloaded again
+        assert_eq!(fetcher.fetch(2022, 1)?, path);
+
+        Ok(())
+    }
+
+    #[test]
+    fn not_available() -> Result<()> {
+        let (url, server) = serve_once("HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n")?;
+        let fetcher = fetcher(url, cache_dir("aoc-fetch-404"));
+
+        let err = fetcher.fetch(2022, 25).unwrap_err();
+        server.join().expect("server finishes");
+
+        assert_eq!(err.to_string(), "day 25 of 2022 isn't available");
+        assert!(!fetcher.cached_path(2022, 25).exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn server_error() -> Result<()> {
+        let (url, server) =
+            serve_once("HTTP/1.1 503 Service Unavailable\r\nContent-Length: 4\r\n\r\nbusy")?;
+        let fetcher = fetcher(url, cache_dir("aoc-fetch-503"));
+
+        let err = fetcher.fetch(2022, 1).unwrap_err();
+        server.join().expect("server finishes");
+
+        assert_eq!(err.to_string(), "the server failed to answer (503)");
+        assert!(!format!("{err:?}").contains("session cookie"));
+        assert!(!fetcher.cached_path(2022, 1).exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_session() {
+        let fetcher = Fetcher {
+            session: None,
+            ..fetcher(
+                "http://127.0.0.1:1".to_string(),
+                cache_dir("aoc-fetch-session"),
+            )
+        };
+
+        assert!(fetcher.fetch(2022, 1).is_err());
+    }
+
+    #[test]
+    fn session_file() -> Result<()> {
+        let file = std::env::temp_dir().join("aoc-fetch-session-file");
+        std::fs::write(&file, "session=abc123\n")?;
+
+        if std::env::var_os(super::SESSION_VAR).is_none() {
+            assert_eq!(session(&file)?.as_deref(), Some("abc123"));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,401 @@
+use std::{
+    ops::RangeInclusive,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    str::FromStr,
+    time::Duration,
+};
+
+use aoc_runner::{
+    answers::Answers,
+    bench::{self, Bench},
+    input, Part, Report, Runner, Solution,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use color_eyre::{
+    eyre::{bail, eyre, WrapErr},
+    Result,
+};
+
+mod fetch;
+mod new_day;
+
+struct Day {
+    year: u32,
+    day: u32,
+    run: Runner,
+}
+
+macro_rules! days {
+    ($($solution:path),* $(,)?) => {
+        &[$(Day {
+            year: <$solution as Solution>::YEAR,
+            day: <$solution as Solution>::DAY,
+            run: aoc_runner::run_part::<$solution>,
+        }),*]
+    };
+}
+
+static DAYS: &[Day] = days![
+    aoc_2022_01::Day01,
+    aoc_2022_02::Day02,
+    aoc_2022_03::Day03,
+    aoc_2022_04::Day04,
+    aoc_2022_05::Day05,
+    aoc_2022_06::Day06,
+    aoc_2022_07::Day07,
+    aoc_2022_08::Day08,
+    aoc_2022_09::Day09,
+    aoc_2022_10::Day10,
+    aoc_2022_11::Day11,
+    aoc_2022_12::Day12,
+    aoc_2022_13::Day13,
+    aoc_2022_14::Day14,
+    aoc_2022_15::Day15,
+    aoc_2022_16::Day16,
+    aoc_2022_17::Day17,
+    aoc_2022_18::Day18,
+    aoc_2022_19::Day19,
+    aoc_2022_20::Day20,
+];
+
+/// Run the Advent of Code solutions
+#[derive(Debug, Parser)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Year of the problems to solve
+    #[arg(required = true)]
+    year: Option<u32>,
+    /// Day (`14`) or inclusive range of days (`1-5`) to run, all of them if missing
+    #[arg(short, long)]
+    day: Option<Days>,
+    /// Only run this part of the problem
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Read the input from this file (`-` for stdin) instead of the default location
+    ///
+    /// The default location is `$AOC_INPUT_DIR/YYYY/NN` if set, or `YYYY/day/NN/input` otherwise.
+    #[arg(short, long, requires = "day")]
+    input: Option<PathBuf>,
+    /// Compare the answers to the ones recorded in `YYYY/day/NN/answers.toml`
+    #[arg(short, long, conflicts_with = "input")]
+    check: bool,
+    /// Record the answers in `YYYY/day/NN/answers.toml` as the new baseline
+    #[arg(long, conflicts_with_all = ["input", "check"])]
+    record: bool,
+    /// Benchmark the solutions by running them this many times instead
+    #[arg(short, long, value_name = "RUNS", conflicts_with_all = ["check", "record"])]
+    bench: Option<usize>,
+    /// Runs before benchmarking that aren't measured
+    #[arg(long, default_value_t = 3, requires = "bench")]
+    warmup: usize,
+    /// How to print the benchmark results
+    #[arg(long, value_enum, default_value_t = Format::Text, requires = "bench")]
+    format: Format,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Create the crate of a new day from the template, keeping any existing files
+    NewDay {
+        /// Year of the problem
+        year: u32,
+        /// Day of the problem
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+    },
+    /// Download the input of a day, unless it was already downloaded
+    Fetch {
+        /// Year of the problem
+        year: u32,
+        /// Day of the problem
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Clone)]
+struct Days(RangeInclusive<u32>);
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |n: &str| {
+            n.trim()
+                .parse::<u32>()
+                .map_err(|err| format!("invalid day `{n}`: {err}"))
+        };
+
+        let (start, end) = match s.split_once('-') {
+            Some((start, end)) => (parse(start)?, parse(end)?),
+            None => (parse(s)?, parse(s)?),
+        };
+
+        if start > end {
+            return Err(format!("empty range of days `{s}`"));
+        }
+
+        Ok(Self(start..=end))
+    }
+}
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+
+    let args = Args::parse();
+
+    match args.command {
+        Some(Command::NewDay { year, day }) => return new_day(year, day),
+        Some(Command::Fetch { year, day }) => return fetch(year, day),
+        None => {}
+    }
+
+    let year = args
+        .year
+        .expect("the year is required without a subcommand");
+    if !DAYS.iter().any(|day| day.year == year) {
+        let mut years: Vec<_> = DAYS.iter().map(|day| day.year.to_string()).collect();
+        years.dedup();
+        bail!("no solutions for {year}, only for {}", years.join(", "));
+    }
+
+    let part = args.part.map(|part| match part {
+        1 => Part::One,
+        _ => Part::Two,
+    });
+
+    let selected: Vec<_> = DAYS
+        .iter()
+        .filter(|day| day.year == year)
+        .filter(|day| {
+            args.day
+                .as_ref()
+                .is_none_or(|Days(days)| days.contains(&day.day))
+        })
+        .collect();
+
+    if selected.is_empty() {
+        bail!("no solutions for the selected days");
+    }
+
+    let source = args
+        .input
+        .as_deref()
+        .map(|path| input::Source::from(path.as_os_str()));
+    if source.is_some() && selected.len() > 1 {
+        bail!("--input can only be used when running a single day");
+    }
+
+    if let Some(runs) = args.bench {
+        return benchmark(
+            &selected,
+            source.as_ref(),
+            part,
+            args.warmup,
+            runs,
+            args.format,
+        );
+    }
+
+    let mut results = vec![];
+    let mut wrong = 0;
+    for day in selected {
+        println!("Day {:02}", day.day);
+
+        let result = input::load(day.year, day.day, source.as_ref()).and_then(|input| {
+            panic::catch_unwind(AssertUnwindSafe(|| (day.run)(input, part)))
+                .unwrap_or_else(|_| Err(eyre!("solution panicked")))
+        });
+
+        match &result {
+            Ok(report) => {
+                let mut answers = String::new();
+                report.write_answers(&mut answers)?;
+                print!("{answers}");
+
+                if args.check {
+                    let check = Answers::load(day.year, day.day)?.check(report);
+                    if check.failed() {
+                        wrong += 1;
+                    }
+                    print!("{check}");
+                }
+                if args.record {
+                    let mut answers = Answers::load(day.year, day.day)?;
+                    answers.record(report);
+                    answers.save(day.year, day.day)?;
+                }
+            }
+            Err(err) => println!("Failed: {err:?}"),
+        }
+        println!();
+
+        results.push((day.year, day.day, result));
+    }
+
+    print_timings(&results);
+
+    let failed = results.iter().filter(|(_, _, res)| res.is_err()).count();
+    if failed > 0 {
+        bail!("{failed} solutions failed");
+    }
+    if wrong > 0 {
+        bail!("{wrong} solutions gave wrong answers");
+    }
+
+    Ok(())
+}
+
+fn new_day(year: u32, day: u32) -> Result<()> {
+    let created = new_day::scaffold(input::workspace_root(), year, day)?;
+    if created.is_empty() {
+        println!("Day {day:02} of {year} already exists, nothing to do");
+        return Ok(());
+    }
+
+    for path in created {
+        println!("Created {}", path.display());
+    }
+    println!(
+        "Add `aoc-{year}-{day:02} = {{ path = \"../../{year}/day/{day:02}\" }}` to \
+         crates/aoc/Cargo.toml and `aoc_{year}_{day:02}::Day{day:02}` to `DAYS` in \
+         crates/aoc/main.rs to run it with the other days"
+    );
+
+    Ok(())
+}
+
+fn fetch(year: u32, day: u32) -> Result<()> {
+    let cached = fetch::Fetcher::new(input::workspace_root())?.fetch(year, day)?;
+    println!("Input cached in {}", cached.display());
+
+    // Also put it where the solutions look for it
+    let path = input::default_path(year, day);
+    if !path.exists() {
+        let dir = path.parent().expect("inputs are inside a directory");
+        std::fs::create_dir_all(dir)
+            .wrap_err_with(|| format!("couldn't create {}", dir.display()))?;
+        std::fs::copy(&cached, &path)
+            .wrap_err_with(|| format!("couldn't copy the input to {}", path.display()))?;
+        println!("Copied to {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn benchmark(
+    selected: &[&Day],
+    source: Option<&input::Source>,
+    part: Option<Part>,
+    warmup: usize,
+    runs: usize,
+    format: Format,
+) -> Result<()> {
+    let mut benches = vec![];
+    let mut failed = 0;
+    for day in selected {
+        // Progress goes to stderr to keep the JSON and CSV output clean
+        eprintln!("Benchmarking day {:02}", day.day);
+
+        let result = input::load(day.year, day.day, source).and_then(|input| {
+            panic::catch_unwind(AssertUnwindSafe(|| {
+                bench::bench(day.run, input, part, warmup, runs)
+            }))
+            .unwrap_or_else(|_| Err(eyre!("solution panicked")))
+        });
+
+        match result {
+            Ok(bench) => benches.push((day.year, day.day, bench)),
+            Err(err) => {
+                eprintln!("Failed: {err:?}");
+                failed += 1;
+            }
+        }
+    }
+
+    match format {
+        Format::Text => print_bench(&benches),
+        Format::Json => println!("{}", bench::to_json(&benches)?),
+        Format::Csv => print!("{}", bench::to_csv(&benches)),
+    }
+
+    if failed > 0 {
+        bail!("{failed} solutions failed");
+    }
+
+    Ok(())
+}
+
+fn print_bench(benches: &[(u32, u32, Bench)]) {
+    println!(
+        "Year | Day | {:<8} | {:>12} | {:>12} | {:>12} | {:>12}",
+        "Step", "Min", "Median", "Mean", "Stddev"
+    );
+    for (year, day, bench) in benches {
+        println!(
+            "-----+-----+{}+{1}+{1}+{1}+{1}",
+            "-".repeat(10),
+            "-".repeat(14)
+        );
+        for (step, stats) in bench.steps() {
+            println!(
+                "{year} | {day:>3} | {step:<8} | {:>12} | {:>12} | {:>12} | {:>12}",
+                format!("{:?}", stats.min),
+                format!("{:?}", stats.median),
+                format!("{:?}", stats.mean),
+                format!("{:?}", stats.stddev),
+            );
+        }
+    }
+}
+
+fn print_timings(results: &[(u32, u32, Result<Report>)]) {
+    fn time(duration: Option<Duration>) -> String {
+        duration.map_or_else(|| "-".to_string(), |d| format!("{d:?}"))
+    }
+
+    println!(
+        "Year | Day | {:>12} | {:>12} | {:>12} | {:>12} | {:>12}",
+        "Parsing", "Processing", "Problem 1", "Problem 2", "Total"
+    );
+    println!("-----+-----+{0}+{0}+{0}+{0}+{0}", "-".repeat(14));
+
+    let mut total = Duration::ZERO;
+    for (year, day, result) in results {
+        let Ok(report) = result else {
+            println!("{year} | {day:>3} | {:>12} |", "failed");
+            continue;
+        };
+
+        let timings = report.timings;
+        total += timings.total;
+        println!(
+            "{year} | {day:>3} | {:>12} | {:>12} | {:>12} | {:>12} | {:>12}",
+            time(Some(timings.parse)),
+            time(Some(timings.prepare)),
+            time(timings.part1),
+            time(timings.part2),
+            time(Some(timings.total)),
+        );
+    }
+
+    println!("-----+-----+{0}+{0}+{0}+{0}+{0}", "-".repeat(14));
+    println!(
+        " All |     | {:>12} | {:>12} | {:>12} | {:>12} | {:>12}",
+        "",
+        "",
+        "",
+        "",
+        time(Some(total))
+    );
+}
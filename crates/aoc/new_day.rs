@@ -0,0 +1,84 @@
+//! Scaffold the crate of a new day from `templates/aoc-template`
+//!
+//! Files that already exist are left untouched, so running it twice is harmless.
+use std::path::{Path, PathBuf};
+
+use color_eyre::{eyre::WrapErr, Result};
+
+/// Template files and where they go inside the day's directory
+static TEMPLATE: &[(&str, &str)] = &[
+    (
+        "Cargo.toml",
+        include_str!("../../templates/aoc-template/Cargo.toml.tmpl"),
+    ),
+    (
+        "lib.rs",
+        include_str!("../../templates/aoc-template/lib.rs.tmpl"),
+    ),
+    (
+        "main.rs",
+        include_str!("../../templates/aoc-template/main.rs.tmpl"),
+    ),
+    ("test_input", ""),
+];
+
+/// Create `root/YYYY/day/NN` from the template, returning the files that were created
+pub fn scaffold(root: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>> {
+    let dir = root.join(format!("{year}/day/{day:02}"));
+    std::fs::create_dir_all(&dir).wrap_err_with(|| format!("couldn't create {}", dir.display()))?;
+
+    let mut created = vec![];
+    for (name, template) in TEMPLATE {
+        let path = dir.join(name);
+        if path.exists() {
+            continue;
+        }
+
+        std::fs::write(&path, render(template, year, day))
+            .wrap_err_with(|| format!("couldn't write {}", path.display()))?;
+        created.push(path);
+    }
+
+    Ok(created)
+}
+
+fn render(template: &str, year: u32, day: u32) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day_zp}}", &format!("{day:02}"))
+        .replace("{{day}}", &day.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::new_day::{render, scaffold};
+    use color_eyre::Result;
+    #[allow(unused)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn render_placeholders() {
+        assert_eq!(
+            render("aoc_{{year}}_{{day_zp}}::Day{{day_zp}} {{day}}", 2022, 5),
+            "aoc_2022_05::Day05 5"
+        );
+    }
+
+    #[test]
+    fn scaffold_is_idempotent() -> Result<()> {
+        let root = std::env::temp_dir().join("aoc-new-day");
+        let _ = std::fs::remove_dir_all(&root);
+
+        let created = scaffold(&root, 2021, 3)?;
+        let dir = root.join("2021/day/03");
+        assert_eq!(created.len(), 4);
+        assert!(std::fs::read_to_string(dir.join("Cargo.toml"))?.contains("aoc-2021-03"));
+        assert!(std::fs::read_to_string(dir.join("lib.rs"))?.contains("const DAY: u32 = 3;"));
+
+        std::fs::write(dir.join("lib.rs"), "// solved")?;
+        assert_eq!(scaffold(&root, 2021, 3)?, Vec::<std::path::PathBuf>::new());
+        assert_eq!(std::fs::read_to_string(dir.join("lib.rs"))?, "// solved");
+
+        Ok(())
+    }
+}
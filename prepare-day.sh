@@ -14,26 +14,15 @@ lang="${3:-rust}"
 
 day_dir="$year/day/$day_zp"
 
-mkdir -pv "$day_dir"
-
 case "$lang" in
 'rust')
-	prev="$PWD"
-	cd "$day_dir" || fail "failed to cd into '$day_dir'"
-	cargo generate --init --name "aoc-$year-$day_zp" --path="$prev"/2022/templates/aoc-template/
-	cd "$prev" || fail "Couldn't cd back to the root"
+	cargo run --quiet -p aoc -- new-day "$year" "$day" ||
+		fail "Couldn't create '$day_dir'"
 	;;
 *)
 	fail "No template for languange '$lang'"
 	;;
 esac
 
-input="$day_dir/input"
-
-if [ -f "session" ]; then
-	session="$(cat "session")"
-else
-	fail "Couldn't get input: Session cookie doesn't exist"
-fi
-
-curl -b "$session" -o "$input" "https://adventofcode.com/$year/day/$day/input" || fail "Couldn't download input for AoC $day $year"
+cargo run --quiet -p aoc -- fetch "$year" "$day" ||
+	fail "Couldn't get input for AoC $day $year"